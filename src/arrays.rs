@@ -0,0 +1,80 @@
+//! Array representations of trees.
+//!
+//! This module provides methods exporting the root subtree as flat arrays in
+//! document order, for numeric code and columnar storage. Nodes are referred
+//! to by their position in the arrays.
+
+use crate::Tree;
+
+impl<T: Clone> Tree<T> {
+    /// Returns the positions of the root subtree nodes in document order,
+    /// indexed by node ID index.
+    fn preorder_positions(&self) -> Vec<usize> {
+        let mut positions = vec![usize::MAX; self.vec.len()];
+        for (position, node) in self.root().descendants().enumerate() {
+            positions[node.id().index()] = position;
+        }
+        positions
+    }
+
+    /// Returns the values of the root subtree in document order, with the
+    /// position of the parent of each node.
+    ///
+    /// Orphans are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ego_tree::tree;
+    ///
+    /// let tree = tree!('a' => { 'b' => { 'c' }, 'd' });
+    /// let (values, parents) = tree.to_parent_array();
+    /// assert_eq!(vec!['a', 'b', 'c', 'd'], values);
+    /// assert_eq!(vec![None, Some(0), Some(1), Some(0)], parents);
+    /// ```
+    pub fn to_parent_array(&self) -> (Vec<T>, Vec<Option<usize>>) {
+        let positions = self.preorder_positions();
+        self.root()
+            .descendants()
+            .map(|node| {
+                let parent = node.parent().map(|parent| positions[parent.id().index()]);
+                (node.value().clone(), parent)
+            })
+            .unzip()
+    }
+
+    /// Returns the values of the root subtree in document order, with the
+    /// positions of the first child and of the next sibling of each node.
+    ///
+    /// Orphans are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ego_tree::tree;
+    ///
+    /// let tree = tree!('a' => { 'b' => { 'c' }, 'd' });
+    /// let (values, first_children, next_siblings) = tree.to_child_sibling_arrays();
+    /// assert_eq!(vec!['a', 'b', 'c', 'd'], values);
+    /// assert_eq!(vec![Some(1), Some(2), None, None], first_children);
+    /// assert_eq!(vec![None, Some(3), None, None], next_siblings);
+    /// ```
+    pub fn to_child_sibling_arrays(&self) -> (Vec<T>, Vec<Option<usize>>, Vec<Option<usize>>) {
+        let positions = self.preorder_positions();
+        let mut values = Vec::new();
+        let mut first_children = Vec::new();
+        let mut next_siblings = Vec::new();
+
+        for (position, node) in self.root().descendants().enumerate() {
+            values.push(node.value().clone());
+            // The first child directly follows its parent in document order.
+            first_children.push(node.has_children().then_some(position + 1));
+            next_siblings.push(
+                node.next_sibling()
+                    .map(|sibling| positions[sibling.id().index()]),
+            );
+        }
+
+        (values, first_children, next_siblings)
+    }
+}
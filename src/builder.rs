@@ -0,0 +1,308 @@
+//! Building trees from runtime data.
+//!
+//! [`TreeBuilder`] constructs a tree from a sequence of `open`, `leaf` and
+//! `close` calls, mirroring the edges yielded by
+//! [`Traverse`](crate::iter::Traverse), without borrowing any node. Trees can
+//! also be built from a stream of [`Event`]s with [`Tree::from_edges`], or
+//! from a table of parent links with [`Tree::from_parent_links`].
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::Hash;
+
+use crate::iter::Edge;
+use crate::{NodeId, Tree};
+
+/// Error encountered while building a tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildError {
+    /// No node was added.
+    Empty,
+    /// A node was added after the root was closed.
+    MultipleRoots,
+    /// `close` was called with no open node.
+    UnbalancedClose,
+    /// The tree was built with this many nodes left open.
+    Unclosed(usize),
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            BuildError::Empty => f.write_str("empty tree"),
+            BuildError::MultipleRoots => f.write_str("multiple roots"),
+            BuildError::UnbalancedClose => f.write_str("close without open node"),
+            BuildError::Unclosed(count) => write!(f, "{count} unclosed nodes"),
+        }
+    }
+}
+
+impl Error for BuildError {}
+
+/// Builder of a tree in document order.
+///
+/// # Examples
+///
+/// ```
+/// use ego_tree::builder::TreeBuilder;
+/// use ego_tree::tree;
+///
+/// # fn main() -> Result<(), ego_tree::builder::BuildError> {
+/// let mut builder = TreeBuilder::new();
+/// builder.open("root")?;
+/// builder.leaf("a")?;
+/// builder.open("b")?;
+/// builder.leaf("c")?;
+/// builder.close()?;
+/// builder.close()?;
+/// assert_eq!(tree!("root" => { "a", "b" => { "c" } }), builder.build()?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TreeBuilder<T> {
+    tree: Option<Tree<T>>,
+    open: Vec<NodeId>,
+}
+
+impl<T> Default for TreeBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> TreeBuilder<T> {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        TreeBuilder {
+            tree: None,
+            open: Vec::new(),
+        }
+    }
+
+    /// Returns the number of open nodes.
+    pub fn depth(&self) -> usize {
+        self.open.len()
+    }
+
+    fn add(&mut self, value: T) -> Result<NodeId, BuildError> {
+        match (&mut self.tree, self.open.last()) {
+            (Some(tree), Some(&parent)) => {
+                Ok(unsafe { tree.get_unchecked_mut(parent) }.append(value).id())
+            }
+            (Some(_), None) => Err(BuildError::MultipleRoots),
+            (tree, _) => Ok(tree.insert(Tree::new(value)).root().id()),
+        }
+    }
+
+    /// Adds a node as the last child of the innermost open node, or as the
+    /// root, and opens it. Returns its ID.
+    pub fn open(&mut self, value: T) -> Result<NodeId, BuildError> {
+        let id = self.add(value)?;
+        self.open.push(id);
+        Ok(id)
+    }
+
+    /// Adds a node as the last child of the innermost open node, or as the
+    /// root, without opening it. Returns its ID.
+    pub fn leaf(&mut self, value: T) -> Result<NodeId, BuildError> {
+        self.add(value)
+    }
+
+    /// Closes the innermost open node, returning its ID.
+    pub fn close(&mut self) -> Result<NodeId, BuildError> {
+        self.open.pop().ok_or(BuildError::UnbalancedClose)
+    }
+
+    /// Returns the built tree.
+    ///
+    /// Fails if no node was added or if nodes are left open.
+    pub fn build(self) -> Result<Tree<T>, BuildError> {
+        match self.tree {
+            None => Err(BuildError::Empty),
+            Some(_) if !self.open.is_empty() => Err(BuildError::Unclosed(self.open.len())),
+            Some(tree) => Ok(tree),
+        }
+    }
+}
+
+/// Owned open or close event of a node.
+///
+/// Consumed by [`Tree::from_edges`]. Edges of an existing tree can be
+/// converted with `From` when values are `Clone`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Event<T> {
+    /// Opens a node with a value.
+    Open(T),
+    /// Closes the innermost open node.
+    Close,
+}
+
+impl<'a, T: Clone + 'a> From<Edge<'a, T>> for Event<T> {
+    fn from(edge: Edge<'a, T>) -> Self {
+        match edge {
+            Edge::Open(node) => Event::Open(node.value().clone()),
+            Edge::Close(_) => Event::Close,
+        }
+    }
+}
+
+impl<T> Tree<T> {
+    /// Builds a tree from a stream of open and close events in document
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ego_tree::builder::Event;
+    /// use ego_tree::{Tree, tree};
+    ///
+    /// let events = [
+    ///     Event::Open("root"),
+    ///     Event::Open("a"),
+    ///     Event::Close,
+    ///     Event::Close,
+    /// ];
+    /// assert_eq!(tree!("root" => { "a" }), Tree::from_edges(events).unwrap());
+    ///
+    /// let tree = tree!(1 => { 2, 3 });
+    /// let copy = Tree::from_edges(tree.root().traverse().map(Event::from)).unwrap();
+    /// assert_eq!(tree, copy);
+    /// ```
+    pub fn from_edges<I>(edges: I) -> Result<Self, BuildError>
+    where
+        I: IntoIterator<Item = Event<T>>,
+    {
+        let mut builder = TreeBuilder::new();
+        for event in edges {
+            match event {
+                Event::Open(value) => builder.open(value)?,
+                Event::Close => builder.close()?,
+            };
+        }
+        builder.build()
+    }
+}
+
+/// Error encountered while building a tree from parent links.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkError<K> {
+    /// No link was given.
+    Empty,
+    /// A second node has no parent.
+    MultipleRoots(K),
+    /// A key is given to more than one node.
+    DuplicateKey(K),
+    /// A parent key is not the key of any node.
+    UnknownParent(K),
+    /// The node with this key is its own ancestor.
+    Cycle(K),
+}
+
+impl<K: Debug> Display for LinkError<K> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            LinkError::Empty => f.write_str("empty tree"),
+            LinkError::MultipleRoots(key) => write!(f, "multiple roots: {key:?}"),
+            LinkError::DuplicateKey(key) => write!(f, "duplicate key: {key:?}"),
+            LinkError::UnknownParent(key) => write!(f, "unknown parent: {key:?}"),
+            LinkError::Cycle(key) => write!(f, "cycle through {key:?}"),
+        }
+    }
+}
+
+impl<K: Debug> Error for LinkError<K> {}
+
+impl<T> Tree<T> {
+    /// Builds a tree from `(key, parent key, value)` links, such as rows of a
+    /// database table.
+    ///
+    /// The root is the only node without a parent. Children are ordered as
+    /// they appear in `links`, which need not list parents before children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ego_tree::{Tree, tree};
+    ///
+    /// let links = [(2, Some(1), "a"), (1, None, "root"), (3, Some(2), "b"), (4, Some(1), "c")];
+    /// let tree = Tree::from_parent_links(links).unwrap();
+    /// assert_eq!(tree!("root" => { "a" => { "b" }, "c" }), tree);
+    /// ```
+    pub fn from_parent_links<K, I>(links: I) -> Result<Self, LinkError<K>>
+    where
+        K: Hash + Eq + Clone,
+        I: IntoIterator<Item = (K, Option<K>, T)>,
+    {
+        let mut keys = Vec::new();
+        let mut parents = Vec::new();
+        let mut values = Vec::new();
+        let mut indices = HashMap::new();
+        let mut root = None;
+
+        for (key, parent, value) in links {
+            let index = keys.len();
+            if indices.insert(key.clone(), index).is_some() {
+                return Err(LinkError::DuplicateKey(key));
+            }
+            if parent.is_none() {
+                if root.is_some() {
+                    return Err(LinkError::MultipleRoots(key));
+                }
+                root = Some(index);
+            }
+            keys.push(key);
+            parents.push(parent);
+            values.push(Some(value));
+        }
+
+        // Resolve parents and collect children in order.
+        let mut parent_indices = vec![None; keys.len()];
+        let mut children = vec![Vec::new(); keys.len()];
+        for (index, parent) in parents.into_iter().enumerate() {
+            let Some(parent) = parent else { continue };
+            let Some(&parent_index) = indices.get(&parent) else {
+                return Err(LinkError::UnknownParent(parent));
+            };
+            parent_indices[index] = Some(parent_index);
+            children[parent_index].push(index);
+        }
+
+        let Some(root) = root else {
+            if keys.is_empty() {
+                return Err(LinkError::Empty);
+            }
+            return Err(cycle(keys, &parent_indices, 0));
+        };
+
+        // Append nodes in document order, so that IDs follow it.
+        let mut tree = Tree::new(values[root].take().unwrap());
+        let mut visited = vec![false; keys.len()];
+        visited[root] = true;
+        let root_id = tree.root().id();
+        let mut stack: Vec<_> = children[root].iter().rev().map(|&c| (root_id, c)).collect();
+        while let Some((parent, index)) = stack.pop() {
+            visited[index] = true;
+            let mut parent = unsafe { tree.get_unchecked_mut(parent) };
+            let id = parent.append(values[index].take().unwrap()).id();
+            stack.extend(children[index].iter().rev().map(|&c| (id, c)));
+        }
+
+        // Nodes not reachable from the root have an ancestor in a cycle.
+        match visited.iter().position(|&visited| !visited) {
+            Some(index) => Err(cycle(keys, &parent_indices, index)),
+            None => Ok(tree),
+        }
+    }
+}
+
+/// Returns a cycle error for a key on the cycle above the node at `index`.
+fn cycle<K>(mut keys: Vec<K>, parents: &[Option<usize>], mut index: usize) -> LinkError<K> {
+    let mut seen = vec![false; keys.len()];
+    while !seen[index] {
+        seen[index] = true;
+        index = parents[index].unwrap();
+    }
+    LinkError::Cycle(keys.swap_remove(index))
+}
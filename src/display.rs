@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+use crate::print::Glyphs;
+
 /// Indentation token
 #[derive(Debug)]
 struct Token {
@@ -9,23 +11,6 @@ struct Token {
     children: bool,
 }
 
-impl Display for Token {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let Token { siblings, children } = self;
-
-        write!(
-            f,
-            "{}",
-            match (siblings, children) {
-                (true, true) => "│   ",
-                (true, false) => "├── ",
-                (false, true) => "    ",
-                (false, false) => "└── ",
-            }
-        )
-    }
-}
-
 impl Token {
     /// Create a new indentation token
     fn new(siblings: bool) -> Self {
@@ -39,33 +24,45 @@ impl Token {
     fn set_children(&mut self) {
         self.children = true;
     }
+
+    /// Glyph drawing this token
+    fn glyph<'g>(&self, glyphs: &Glyphs<'g>) -> &'g str {
+        match (self.siblings, self.children) {
+            (true, true) => glyphs.vertical,
+            (true, false) => glyphs.branch,
+            (false, true) => glyphs.space,
+            (false, false) => glyphs.last_branch,
+        }
+    }
 }
 
 /// Manages the state during the display operation
 #[derive(Debug)]
-pub struct Indentation {
+pub struct Indentation<'g> {
     tokens: Vec<Token>,
     ignore_root: bool,
+    glyphs: Glyphs<'g>,
 }
 
-impl Display for Indentation {
+impl Display for Indentation<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         let first: usize = if self.ignore_root { 1 } else { 0 };
 
-        for token in &self.tokens[first..] {
-            write!(f, "{token}")?;
+        for token in self.tokens.iter().skip(first) {
+            f.write_str(token.glyph(&self.glyphs))?;
         }
 
         Ok(())
     }
 }
 
-impl Indentation {
+impl<'g> Indentation<'g> {
     /// Creates a new indentation handler
-    pub fn new(ignore_root: bool) -> Self {
+    pub fn new(ignore_root: bool, glyphs: Glyphs<'g>) -> Self {
         Indentation {
             tokens: Vec::new(),
             ignore_root,
+            glyphs,
         }
     }
 
@@ -0,0 +1,178 @@
+//! Graphviz DOT export.
+//!
+//! [`Dot`] writes a tree as a Graphviz digraph, with configurable node labels
+//! and attributes. It can also draw sibling links and orphan nodes, which is
+//! useful to inspect the structure of a tree visually.
+
+use std::fmt::{self, Debug, Display, Formatter, Write};
+
+use crate::{NodeRef, Tree};
+
+type NodeFn<'a, T> = Box<dyn Fn(NodeRef<'a, T>) -> String + 'a>;
+
+/// Builder writing a tree in the Graphviz DOT format.
+///
+/// Nodes are named after the index of their ID.
+///
+/// # Examples
+///
+/// ```
+/// use ego_tree::{dot::Dot, tree};
+///
+/// let tree = tree!("root" => { "a", "b" });
+/// let dot = Dot::new(&tree).attributes(|node| {
+///     if node.has_children() { "shape=box".into() } else { String::new() }
+/// });
+/// assert_eq!(
+///     dot.to_string(),
+///     "digraph {\n    \
+///         0 [label=\"root\", shape=box];\n    \
+///         1 [label=\"a\"];\n    \
+///         2 [label=\"b\"];\n    \
+///         0 -> 1;\n    \
+///         0 -> 2;\n\
+///     }\n",
+/// );
+/// ```
+pub struct Dot<'a, T: 'a> {
+    tree: &'a Tree<T>,
+    label: NodeFn<'a, T>,
+    attributes: Option<NodeFn<'a, T>>,
+    sibling_edges: bool,
+    orphans: bool,
+}
+
+impl<'a, T: 'a> Debug for Dot<'a, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Dot")
+            .field("sibling_edges", &self.sibling_edges)
+            .field("orphans", &self.orphans)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T: Display + 'a> Dot<'a, T> {
+    /// Creates a DOT writer for a tree, labelling nodes with `Display`.
+    pub fn new(tree: &'a Tree<T>) -> Self {
+        Self::with_label(tree, |node| node.value().to_string())
+    }
+}
+
+impl<'a, T: 'a> Dot<'a, T> {
+    /// Creates a DOT writer for a tree, labelling nodes with `label`.
+    pub fn with_label<F>(tree: &'a Tree<T>, label: F) -> Self
+    where
+        F: Fn(NodeRef<'a, T>) -> String + 'a,
+    {
+        Dot {
+            tree,
+            label: Box::new(label),
+            attributes: None,
+            sibling_edges: false,
+            orphans: false,
+        }
+    }
+
+    /// Sets the function labelling each node.
+    pub fn label<F>(mut self, label: F) -> Self
+    where
+        F: Fn(NodeRef<'a, T>) -> String + 'a,
+    {
+        self.label = Box::new(label);
+        self
+    }
+
+    /// Sets the function returning extra attributes of each node, written
+    /// verbatim after its label, such as `shape=box, color=red`.
+    pub fn attributes<F>(mut self, attributes: F) -> Self
+    where
+        F: Fn(NodeRef<'a, T>) -> String + 'a,
+    {
+        self.attributes = Some(Box::new(attributes));
+        self
+    }
+
+    /// Sets whether to draw dashed edges from each node to its next sibling.
+    pub fn sibling_edges(mut self, sibling_edges: bool) -> Self {
+        self.sibling_edges = sibling_edges;
+        self
+    }
+
+    /// Sets whether to draw every node of the tree in insert order, including
+    /// orphans, rather than only the root subtree in document order.
+    pub fn orphans(mut self, orphans: bool) -> Self {
+        self.orphans = orphans;
+        self
+    }
+
+    fn nodes(&self) -> Box<dyn Iterator<Item = NodeRef<'a, T>> + 'a> {
+        if self.orphans {
+            Box::new(self.tree.nodes())
+        } else {
+            Box::new(self.tree.root().descendants())
+        }
+    }
+}
+
+/// Writes a string as a quoted DOT identifier.
+fn write_quoted(f: &mut Formatter, s: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+impl<'a, T: 'a> Display for Dot<'a, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(f, "digraph {{")?;
+
+        for node in self.nodes() {
+            write!(f, "    {} [label=", node.id())?;
+            write_quoted(f, &(self.label)(node))?;
+            if let Some(attributes) = &self.attributes {
+                let attributes = attributes(node);
+                if !attributes.is_empty() {
+                    write!(f, ", {attributes}")?;
+                }
+            }
+            writeln!(f, "];")?;
+        }
+
+        for node in self.nodes() {
+            if let Some(parent) = node.parent() {
+                writeln!(f, "    {} -> {};", parent.id(), node.id())?;
+            }
+        }
+
+        if self.sibling_edges {
+            for node in self.nodes() {
+                if let Some(next_sibling) = node.next_sibling() {
+                    writeln!(
+                        f,
+                        "    {} -> {} [style=dashed, constraint=false];",
+                        node.id(),
+                        next_sibling.id(),
+                    )?;
+                }
+            }
+        }
+
+        writeln!(f, "}}")
+    }
+}
+
+impl<T: Display> Tree<T> {
+    /// Returns the root subtree in the Graphviz DOT format, labelling nodes
+    /// with `Display`.
+    ///
+    /// Use [`Dot`] for more options.
+    pub fn to_dot(&self) -> String {
+        Dot::new(self).to_string()
+    }
+}
@@ -0,0 +1,133 @@
+//! Filtering functionality for trees.
+//!
+//! This module provides methods for building a tree containing only the nodes
+//! matching a predicate, and for detaching non-matching nodes in place.
+
+use crate::iter::Edge;
+use crate::node_map::NodeMap;
+use crate::{FilterMode, NodeId, NodeMut, NodeRef, Tree};
+
+impl<T: Clone> Tree<T> {
+    /// Creates a new tree containing clones of the nodes of the root subtree
+    /// for which `pred` returns true, in the same order.
+    ///
+    /// Returns the new tree along with a mapping from the IDs of the kept
+    /// nodes to their IDs in the new tree, or `None` if `pred` returns false
+    /// for the root. With [`FilterMode::Prune`], `pred` is not called for the
+    /// descendants of removed nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ego_tree::{FilterMode, tree};
+    ///
+    /// let tree = tree!(1 => { 2 => { 3, 4 }, 5 });
+    ///
+    /// let (pruned, _) = tree.filter(FilterMode::Prune, |n| *n.value() != 2).unwrap();
+    /// assert_eq!(tree!(1 => { 5 }), pruned);
+    ///
+    /// let (hoisted, _) = tree.filter(FilterMode::Hoist, |n| *n.value() != 2).unwrap();
+    /// assert_eq!(tree!(1 => { 3, 4, 5 }), hoisted);
+    /// ```
+    pub fn filter<F>(&self, mode: FilterMode, mut pred: F) -> Option<(Tree<T>, NodeMap<NodeId>)>
+    where
+        F: FnMut(NodeRef<T>) -> bool,
+    {
+        let root = self.root();
+        if !pred(root) {
+            return None;
+        }
+
+        let mut tree = Tree::new(root.value().clone());
+        let mut ids = NodeMap::new();
+        ids.insert(root.id, tree.root().id);
+
+        // New IDs of the kept ancestors of the current node.
+        let mut parents = vec![tree.root().id];
+        // Whether each open ancestor of the current node was kept.
+        let mut kept = Vec::new();
+        // Removed node whose subtree is being skipped.
+        let mut skip = None;
+
+        for edge in root.traverse().skip(1) {
+            match edge {
+                Edge::Open(_) if skip.is_some() => {}
+                Edge::Open(node) => {
+                    if pred(node) {
+                        let parent = *parents.last().unwrap();
+                        let mut parent = unsafe { tree.get_unchecked_mut(parent) };
+                        let id = parent.append(node.value().clone()).id;
+                        ids.insert(node.id, id);
+                        parents.push(id);
+                        kept.push(true);
+                    } else {
+                        match mode {
+                            FilterMode::Prune => skip = Some(node),
+                            FilterMode::Hoist => kept.push(false),
+                        }
+                    }
+                }
+                Edge::Close(node) => {
+                    if skip.is_some() {
+                        if skip == Some(node) {
+                            skip = None;
+                        }
+                    } else if kept.pop() == Some(true) {
+                        parents.pop();
+                    }
+                }
+            }
+        }
+
+        Some((tree, ids))
+    }
+}
+
+impl<'a, T: 'a> NodeMut<'a, T> {
+    /// Detaches the descendants of this node for which `pred` returns false,
+    /// along with their subtrees.
+    ///
+    /// Detached nodes remain in the tree as orphans. `pred` is not called for
+    /// this node nor for the descendants of detached nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ego_tree::tree;
+    ///
+    /// let mut tree = tree!(1 => { 2 => { 3 }, 4 => { 5, 6 } });
+    /// tree.root_mut().retain_descendants(|n| *n.value() != 2 && *n.value() != 5);
+    /// assert_eq!(tree!(1 => { 4 => { 6 } }).to_string(), tree.to_string());
+    /// ```
+    pub fn retain_descendants<F>(&mut self, mut pred: F)
+    where
+        F: FnMut(NodeRef<T>) -> bool,
+    {
+        let removed = {
+            let this = unsafe { self.tree.get_unchecked(self.id) };
+            let mut removed = Vec::new();
+            let mut skip = None;
+            for edge in this.traverse().skip(1) {
+                match edge {
+                    Edge::Open(_) if skip.is_some() => {}
+                    Edge::Open(node) => {
+                        if !pred(node) {
+                            removed.push(node.id);
+                            skip = Some(node);
+                        }
+                    }
+                    Edge::Close(node) => {
+                        if skip == Some(node) {
+                            skip = None;
+                        }
+                    }
+                }
+            }
+            removed
+        };
+
+        for id in removed {
+            unsafe { self.tree.get_unchecked_mut(id).detach() };
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Search functionality for trees.
+//!
+//! This module provides methods for finding nodes matching a predicate in
+//! document order, stopping at the first match.
+
+use std::iter::Filter;
+
+use crate::iter::Descendants;
+use crate::{NodeRef, Tree};
+
+impl<T> Tree<T> {
+    /// Returns the first node of the root subtree, in document order, for
+    /// which `pred` returns true.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ego_tree::tree;
+    ///
+    /// let tree = tree!(1 => { 2 => { 3 }, 4 });
+    /// assert_eq!(&3, tree.find(|n| *n.value() > 2).unwrap().value());
+    /// ```
+    pub fn find<F>(&self, pred: F) -> Option<NodeRef<'_, T>>
+    where
+        F: FnMut(&NodeRef<'_, T>) -> bool,
+    {
+        self.root().descendants().find(pred)
+    }
+
+    /// Returns an iterator over the nodes of the root subtree, in document
+    /// order, for which `pred` returns true.
+    pub fn find_all<'a, F>(&'a self, pred: F) -> Filter<Descendants<'a, T>, F>
+    where
+        F: FnMut(&NodeRef<'a, T>) -> bool,
+    {
+        self.root().descendants().filter(pred)
+    }
+}
+
+impl<'a, T: 'a> NodeRef<'a, T> {
+    /// Returns the first descendant of this node, in document order, for
+    /// which `pred` returns true.
+    ///
+    /// Unlike `descendants().find(pred)`, this node itself is not tested.
+    pub fn find_descendant<F>(&self, pred: F) -> Option<Self>
+    where
+        F: FnMut(&Self) -> bool,
+    {
+        self.descendants().skip(1).find(pred)
+    }
+
+    /// Returns the nearest ancestor of this node for which `pred` returns
+    /// true.
+    pub fn find_ancestor<F>(&self, pred: F) -> Option<Self>
+    where
+        F: FnMut(&Self) -> bool,
+    {
+        self.ancestors().find(pred)
+    }
+
+    /// Returns the number of siblings preceding this node.
+    ///
+    /// Unlike [`NodeRef::index_in_parent`], this is `0` for nodes without a
+    /// parent.
+    pub fn position_among_siblings(&self) -> usize {
+        self.prev_siblings().count()
+    }
+}
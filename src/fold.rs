@@ -0,0 +1,100 @@
+//! Folding functionality for subtrees.
+//!
+//! This module provides methods computing a value for every node of a
+//! subtree, either from the values of its children or from the value of its
+//! parent, without recursion. Results are keyed by the IDs of the folded
+//! nodes.
+
+use crate::iter::Edge;
+use crate::node_map::NodeMap;
+use crate::{NodeId, NodeRef};
+
+impl<'a, T: 'a> NodeRef<'a, T> {
+    /// Computes a value for each node of this subtree from the values of its
+    /// children, bottom-up.
+    ///
+    /// Leaves get `leaf(value)`; other nodes get `combine(value, children)`,
+    /// where `children` holds the results of their children in order. Returns
+    /// the results keyed by node ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ego_tree::tree;
+    ///
+    /// let tree = tree!('a' => { 'b' => { 'c' }, 'd' });
+    /// let heights = tree.root().fold_up(
+    ///     |_| 0,
+    ///     |_, children| 1 + children.iter().max().unwrap(),
+    /// );
+    /// assert_eq!(2, heights[tree.root().id()]);
+    /// assert_eq!(1, heights[tree.root().first_child().unwrap().id()]);
+    /// ```
+    pub fn fold_up<U, F, G>(&self, mut leaf: F, mut combine: G) -> NodeMap<U>
+    where
+        F: FnMut(&'a T) -> U,
+        G: FnMut(&'a T, &[U]) -> U,
+    {
+        let mut results = NodeMap::new();
+        // Results of closed nodes whose parent is still open, and their IDs.
+        let mut pending = Vec::new();
+        let mut pending_ids: Vec<NodeId> = Vec::new();
+        // Length of `pending` when each open node was opened.
+        let mut open = Vec::new();
+
+        for edge in self.traverse() {
+            match edge {
+                Edge::Open(_) => open.push(pending.len()),
+                Edge::Close(node) => {
+                    let mark = open.pop().unwrap();
+                    let result = if node.has_children() {
+                        combine(node.value(), &pending[mark..])
+                    } else {
+                        leaf(node.value())
+                    };
+                    results.extend(pending_ids.drain(mark..).zip(pending.drain(mark..)));
+                    pending.push(result);
+                    pending_ids.push(node.id());
+                }
+            }
+        }
+        results.extend(pending_ids.into_iter().zip(pending));
+
+        results
+    }
+
+    /// Computes a value for each node of this subtree from the value of its
+    /// parent, top-down.
+    ///
+    /// This node gets `f(&init, value)`; its descendants get
+    /// `f(parent, value)`, where `parent` is the result of their parent.
+    /// Returns the results keyed by node ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ego_tree::tree;
+    ///
+    /// let tree = tree!("a" => { "b" => { "c" }, "d" });
+    /// let paths = tree.root().fold_down(String::new(), |parent, value| {
+    ///     format!("{parent}/{value}")
+    /// });
+    /// let c = tree.root().first_child().unwrap().first_child().unwrap();
+    /// assert_eq!("/a/b/c", paths[c.id()]);
+    /// ```
+    pub fn fold_down<U, F>(&self, init: U, mut f: F) -> NodeMap<U>
+    where
+        F: FnMut(&U, &'a T) -> U,
+    {
+        let mut results = NodeMap::new();
+        results.insert(self.id, f(&init, self.value()));
+
+        for node in self.descendants().skip(1) {
+            let parent = node.parent().unwrap().id;
+            let value = f(&results[parent], node.value());
+            results.insert(node.id, value);
+        }
+
+        results
+    }
+}
@@ -0,0 +1,121 @@
+//! Text formats for trees.
+//!
+//! Readers and writers for common textual tree representations. Labels are
+//! converted to and from node values by user functions. Both reading and
+//! writing are iterative, so deep trees do not overflow the stack.
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+pub mod newick;
+pub mod sexpr;
+
+/// Kind of error encountered while reading a tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatErrorKind<E> {
+    /// The input ended before the tree was complete.
+    UnexpectedEnd,
+    /// A character was not expected at this position.
+    UnexpectedChar(char),
+    /// A branch length is not a valid number.
+    InvalidLength,
+    /// The input continues after the tree.
+    TrailingInput,
+    /// A label was rejected by the label function.
+    Label(E),
+}
+
+/// Error encountered while reading a tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatError<E> {
+    /// Line of the error, starting from 1.
+    pub line: usize,
+    /// Column of the error in characters, starting from 1.
+    pub column: usize,
+    /// Kind of the error.
+    pub kind: FormatErrorKind<E>,
+}
+
+impl<E> FormatError<E> {
+    /// Creates an error at byte offset `pos` of `input`.
+    fn at(input: &str, pos: usize, kind: FormatErrorKind<E>) -> Self {
+        let before = &input[..pos];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        FormatError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            kind,
+        }
+    }
+}
+
+impl<E: Display> Display for FormatError<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        match &self.kind {
+            FormatErrorKind::UnexpectedEnd => f.write_str("unexpected end of input"),
+            FormatErrorKind::UnexpectedChar(c) => write!(f, "unexpected character {c:?}"),
+            FormatErrorKind::InvalidLength => f.write_str("invalid branch length"),
+            FormatErrorKind::TrailingInput => f.write_str("trailing input"),
+            FormatErrorKind::Label(error) => write!(f, "invalid label: {error}"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for FormatError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            FormatErrorKind::Label(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Input cursor shared by the readers.
+struct Cursor<'s> {
+    input: &'s str,
+    pos: usize,
+}
+
+impl<'s> Cursor<'s> {
+    fn new(input: &'s str) -> Self {
+        Cursor { input, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    /// Consumes characters while `pred` holds, returning them.
+    fn take_while<F: Fn(char) -> bool>(&mut self, pred: F) -> &'s str {
+        let start = self.pos;
+        while self.peek().is_some_and(&pred) {
+            self.bump();
+        }
+        &self.input[start..self.pos]
+    }
+
+    fn error<E>(&self, kind: FormatErrorKind<E>) -> FormatError<E> {
+        FormatError::at(self.input, self.pos, kind)
+    }
+
+    /// Returns an error for the next character, or for the end of input.
+    fn unexpected<E>(&self) -> FormatError<E> {
+        match self.peek() {
+            Some(c) => self.error(FormatErrorKind::UnexpectedChar(c)),
+            None => self.error(FormatErrorKind::UnexpectedEnd),
+        }
+    }
+}
@@ -0,0 +1,205 @@
+//! Newick format.
+//!
+//! Reads and writes trees such as `((A:0.1,B:0.2)C,D)E;`, where each node has
+//! an optional name and an optional branch length, and children are listed
+//! in parentheses before their parent. Names may be quoted with `'`, a quote
+//! being escaped as `''`. Underscores in unquoted names are kept as is.
+
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+
+use super::{Cursor, FormatError, FormatErrorKind};
+use crate::iter::Edge;
+use crate::{NodeRef, Tree};
+
+const DELIMITERS: &str = "()[]':;,";
+
+fn is_name_char(c: char) -> bool {
+    !c.is_whitespace() && !DELIMITERS.contains(c)
+}
+
+/// Reads a tree in the Newick format.
+///
+/// `label` converts the name and branch length of each node to its value.
+///
+/// # Examples
+///
+/// ```
+/// use ego_tree::format::newick;
+/// use ego_tree::tree;
+///
+/// let tree = newick::parse("(A:0.1,B:0.2)C;", |name, length| {
+///     Ok::<_, ()>((name.to_string(), length))
+/// })
+/// .unwrap();
+/// assert_eq!(
+///     tree!(("C".to_string(), None) => {
+///         ("A".to_string(), Some(0.1)),
+///         ("B".to_string(), Some(0.2)),
+///     }),
+///     tree
+/// );
+/// ```
+pub fn parse<T, E, F>(input: &str, mut label: F) -> Result<Tree<T>, FormatError<E>>
+where
+    F: FnMut(&str, Option<f64>) -> Result<T, E>,
+{
+    let mut cursor = Cursor::new(input);
+
+    // Values are only known after the children, so build with placeholders.
+    let mut tree = Tree::new(None);
+    let mut current = tree.root().id;
+    let mut parents = Vec::new();
+    let mut start = true;
+
+    loop {
+        cursor.skip_whitespace();
+
+        if start && cursor.peek() == Some('(') {
+            cursor.bump();
+            parents.push(current);
+            current = unsafe { tree.get_unchecked_mut(current) }.append(None).id;
+            continue;
+        }
+
+        let label_pos = cursor.pos;
+        let name = parse_name(&mut cursor)?;
+        cursor.skip_whitespace();
+        let length = if cursor.peek() == Some(':') {
+            cursor.bump();
+            cursor.skip_whitespace();
+            let length_pos = cursor.pos;
+            let length = cursor.take_while(is_name_char);
+            match length.parse() {
+                Ok(length) => Some(length),
+                Err(_) => {
+                    return Err(FormatError::at(
+                        input,
+                        length_pos,
+                        FormatErrorKind::InvalidLength,
+                    ));
+                }
+            }
+        } else {
+            None
+        };
+        let value = label(&name, length)
+            .map_err(|e| FormatError::at(input, label_pos, FormatErrorKind::Label(e)))?;
+        *unsafe { tree.get_unchecked_mut(current) }.value() = Some(value);
+
+        cursor.skip_whitespace();
+        match cursor.peek() {
+            Some(',') if !parents.is_empty() => {
+                cursor.bump();
+                let parent = *parents.last().unwrap();
+                current = unsafe { tree.get_unchecked_mut(parent) }.append(None).id;
+                start = true;
+            }
+            Some(')') if !parents.is_empty() => {
+                cursor.bump();
+                current = parents.pop().unwrap();
+                start = false;
+            }
+            Some(';') if parents.is_empty() => {
+                cursor.bump();
+                break;
+            }
+            _ => return Err(cursor.unexpected()),
+        }
+    }
+
+    cursor.skip_whitespace();
+    if cursor.peek().is_some() {
+        return Err(cursor.error(FormatErrorKind::TrailingInput));
+    }
+    Ok(tree.map(Option::unwrap))
+}
+
+fn parse_name<'s, E>(cursor: &mut Cursor<'s>) -> Result<Cow<'s, str>, FormatError<E>> {
+    if cursor.peek() != Some('\'') {
+        return Ok(Cow::Borrowed(cursor.take_while(is_name_char)));
+    }
+
+    cursor.bump();
+    let mut name = String::new();
+    loop {
+        match cursor.bump() {
+            Some('\'') if cursor.peek() == Some('\'') => {
+                cursor.bump();
+                name.push('\'');
+            }
+            Some('\'') => return Ok(Cow::Owned(name)),
+            Some(c) => name.push(c),
+            None => return Err(cursor.error(FormatErrorKind::UnexpectedEnd)),
+        }
+    }
+}
+
+fn write_label<W: Write>(out: &mut W, name: &str, length: Option<f64>) -> fmt::Result {
+    if name.chars().all(is_name_char) {
+        out.write_str(name)?;
+    } else {
+        write!(out, "'{}'", name.replace('\'', "''"))?;
+    }
+    if let Some(length) = length {
+        write!(out, ":{length}")?;
+    }
+    Ok(())
+}
+
+/// Writes the subtree starting at `node` in the Newick format.
+///
+/// `label` converts the value of each node to its name and branch length.
+/// Names are quoted when needed.
+pub fn write<W, T, F>(out: &mut W, node: NodeRef<T>, mut label: F) -> fmt::Result
+where
+    W: Write,
+    F: FnMut(&T) -> (String, Option<f64>),
+{
+    for edge in node.traverse() {
+        match edge {
+            Edge::Open(open) => {
+                if open != node && open.prev_sibling().is_some() {
+                    out.write_char(',')?;
+                }
+                if open.has_children() {
+                    out.write_char('(')?;
+                } else {
+                    let (name, length) = label(open.value());
+                    write_label(out, &name, length)?;
+                }
+            }
+            Edge::Close(close) => {
+                if close.has_children() {
+                    out.write_char(')')?;
+                    let (name, length) = label(close.value());
+                    write_label(out, &name, length)?;
+                }
+            }
+        }
+    }
+    out.write_char(';')
+}
+
+/// Returns the subtree starting at `node` in the Newick format.
+///
+/// See [`write()`].
+///
+/// # Examples
+///
+/// ```
+/// use ego_tree::format::newick;
+/// use ego_tree::tree;
+///
+/// let tree = tree!(("C", None) => { ("A", Some(0.1)), ("B b", None) });
+/// let newick = newick::to_string(tree.root(), |&(name, length)| (name.to_string(), length));
+/// assert_eq!("(A:0.1,'B b')C;", newick);
+/// ```
+pub fn to_string<T, F>(node: NodeRef<T>, label: F) -> String
+where
+    F: FnMut(&T) -> (String, Option<f64>),
+{
+    let mut out = String::new();
+    write(&mut out, node, label).unwrap();
+    out
+}
@@ -0,0 +1,181 @@
+//! S-expression format.
+//!
+//! Reads and writes trees such as `(root leaf (node child))`, where a list
+//! starts with the label of a node followed by its children, and a bare atom
+//! is a leaf. Atoms may be quoted with `"`, using `\"` and `\\` as escapes.
+
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+
+use super::{Cursor, FormatError, FormatErrorKind};
+use crate::iter::Edge;
+use crate::{NodeId, NodeRef, Tree};
+
+fn is_atom_char(c: char) -> bool {
+    !c.is_whitespace() && !"()\"".contains(c)
+}
+
+/// Reads a tree in the S-expression format.
+///
+/// `label` converts the atom of each node to its value.
+///
+/// # Examples
+///
+/// ```
+/// use ego_tree::format::sexpr;
+/// use ego_tree::tree;
+///
+/// let tree = sexpr::parse("(+ 1 (* 2 3))", |atom| Ok::<_, ()>(atom.to_string())).unwrap();
+/// assert_eq!(
+///     tree!("+" => { "1", "*" => { "2", "3" } }).map(String::from),
+///     tree
+/// );
+/// ```
+pub fn parse<T, E, F>(input: &str, mut label: F) -> Result<Tree<T>, FormatError<E>>
+where
+    F: FnMut(&str) -> Result<T, E>,
+{
+    let mut cursor = Cursor::new(input);
+    let mut tree: Option<Tree<T>> = None;
+    // IDs of the nodes whose list is open.
+    let mut open: Vec<NodeId> = Vec::new();
+
+    loop {
+        cursor.skip_whitespace();
+        if tree.is_some() && open.is_empty() {
+            break;
+        }
+
+        let list = match cursor.peek() {
+            Some('(') => {
+                cursor.bump();
+                cursor.skip_whitespace();
+                true
+            }
+            Some(')') if !open.is_empty() => {
+                cursor.bump();
+                open.pop();
+                continue;
+            }
+            _ => false,
+        };
+
+        let atom_pos = cursor.pos;
+        let atom = parse_atom(&mut cursor)?;
+        let value = label(&atom)
+            .map_err(|e| FormatError::at(input, atom_pos, FormatErrorKind::Label(e)))?;
+
+        let id = match (&mut tree, open.last()) {
+            (Some(tree), Some(&parent)) => {
+                unsafe { tree.get_unchecked_mut(parent) }.append(value).id
+            }
+            (tree, _) => tree.insert(Tree::new(value)).root().id,
+        };
+        if list {
+            open.push(id);
+        }
+    }
+
+    if cursor.peek().is_some() {
+        return Err(cursor.error(FormatErrorKind::TrailingInput));
+    }
+    Ok(tree.unwrap())
+}
+
+fn parse_atom<'s, E>(cursor: &mut Cursor<'s>) -> Result<Cow<'s, str>, FormatError<E>> {
+    match cursor.peek() {
+        Some('"') => {}
+        Some(c) if is_atom_char(c) => return Ok(Cow::Borrowed(cursor.take_while(is_atom_char))),
+        _ => return Err(cursor.unexpected()),
+    }
+
+    cursor.bump();
+    let mut atom = String::new();
+    loop {
+        match cursor.bump() {
+            Some('\\') => {
+                let escape_pos = cursor.pos;
+                match cursor.bump() {
+                    Some(c @ ('"' | '\\')) => atom.push(c),
+                    Some(c) => {
+                        let kind = FormatErrorKind::UnexpectedChar(c);
+                        return Err(FormatError::at(cursor.input, escape_pos, kind));
+                    }
+                    None => return Err(cursor.error(FormatErrorKind::UnexpectedEnd)),
+                }
+            }
+            Some('"') => return Ok(Cow::Owned(atom)),
+            Some(c) => atom.push(c),
+            None => return Err(cursor.error(FormatErrorKind::UnexpectedEnd)),
+        }
+    }
+}
+
+fn write_atom<W: Write>(out: &mut W, atom: &str) -> fmt::Result {
+    if !atom.is_empty() && atom.chars().all(is_atom_char) {
+        return out.write_str(atom);
+    }
+    out.write_char('"')?;
+    for c in atom.chars() {
+        if c == '"' || c == '\\' {
+            out.write_char('\\')?;
+        }
+        out.write_char(c)?;
+    }
+    out.write_char('"')
+}
+
+/// Writes the subtree starting at `node` in the S-expression format.
+///
+/// `label` converts the value of each node to its atom. Atoms are quoted
+/// when needed.
+pub fn write<W, T, F>(out: &mut W, node: NodeRef<T>, mut label: F) -> fmt::Result
+where
+    W: Write,
+    F: FnMut(&T) -> String,
+{
+    for edge in node.traverse() {
+        match edge {
+            Edge::Open(open) => {
+                if open != node {
+                    out.write_char(' ')?;
+                }
+                if open.has_children() {
+                    out.write_char('(')?;
+                }
+                write_atom(out, &label(open.value()))?;
+            }
+            Edge::Close(close) => {
+                if close.has_children() {
+                    out.write_char(')')?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the subtree starting at `node` in the S-expression format.
+///
+/// See [`write()`].
+///
+/// # Examples
+///
+/// ```
+/// use ego_tree::format::sexpr;
+/// use ego_tree::tree;
+///
+/// let tree = tree!("define" => { "x", "+" => { "1", "two words" } });
+/// assert_eq!(
+///     r#"(define x (+ 1 "two words"))"#,
+///     sexpr::to_string(tree.root(), |atom| atom.to_string())
+/// );
+/// ```
+pub fn to_string<T, F>(node: NodeRef<T>, label: F) -> String
+where
+    F: FnMut(&T) -> String,
+{
+    let mut out = String::new();
+    write(&mut out, node, label).unwrap();
+    out
+}
@@ -0,0 +1,163 @@
+//! Precomputed index for constant time ancestry queries.
+//!
+//! Building a [`TreeIndex`] numbers every node in pre-order once, after which
+//! ancestry tests and subtree sizes are answered in constant time and lowest
+//! common ancestors in logarithmic time. The index borrows the tree, so the
+//! tree cannot be modified while the index is alive and the index can never
+//! go stale.
+
+use crate::iter::Edge;
+use crate::{NodeId, NodeRef, Tree};
+
+/// Precomputed ancestry index of a tree.
+///
+/// Covers the root subtree as well as every orphan subtree in the tree.
+///
+/// # Examples
+///
+/// ```
+/// use ego_tree::{index::TreeIndex, tree};
+///
+/// let tree = tree!('a' => { 'b' => { 'c' }, 'd' });
+/// let index = TreeIndex::new(&tree);
+///
+/// let b = tree.root().first_child().unwrap().id();
+/// let c = tree.root().first_child().unwrap().first_child().unwrap().id();
+/// let d = tree.root().last_child().unwrap().id();
+///
+/// assert!(index.is_ancestor_of(b, c));
+/// assert_eq!(2, index.subtree_size(b));
+/// assert_eq!(tree.root(), index.lowest_common_ancestor(c, d).unwrap());
+/// ```
+#[derive(Debug)]
+pub struct TreeIndex<'a, T: 'a> {
+    tree: &'a Tree<T>,
+    /// Pre-order number of each node.
+    pre: Vec<usize>,
+    /// Pre-order number of the last descendant of each node.
+    last: Vec<usize>,
+    /// Depth of each node.
+    depth: Vec<usize>,
+    /// Index of the `2^k`-th ancestor of each node, or of its root.
+    up: Vec<Vec<usize>>,
+}
+
+impl<'a, T: 'a> Clone for TreeIndex<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            tree: self.tree,
+            pre: self.pre.clone(),
+            last: self.last.clone(),
+            depth: self.depth.clone(),
+            up: self.up.clone(),
+        }
+    }
+}
+
+impl<'a, T: 'a> TreeIndex<'a, T> {
+    /// Builds the index of a tree in linearithmic time.
+    pub fn new(tree: &'a Tree<T>) -> Self {
+        let len = tree.vec.len();
+        let mut pre = vec![0; len];
+        let mut last = vec![0; len];
+        let mut depth = vec![0; len];
+        let mut parent = (0..len).collect::<Vec<_>>();
+
+        let mut counter = 0;
+        for root in tree.nodes().filter(|node| node.parent().is_none()) {
+            for edge in root.traverse() {
+                match edge {
+                    Edge::Open(node) => {
+                        let i = node.id.to_index();
+                        pre[i] = counter;
+                        counter += 1;
+                        if let Some(p) = node.parent() {
+                            parent[i] = p.id.to_index();
+                            depth[i] = depth[parent[i]] + 1;
+                        }
+                    }
+                    Edge::Close(node) => {
+                        last[node.id.to_index()] = counter - 1;
+                    }
+                }
+            }
+        }
+
+        let mut up = vec![parent];
+        let max_depth = depth.iter().copied().max().unwrap_or(0);
+        while 1 << up.len() <= max_depth {
+            let prev = up.last().unwrap();
+            let next = prev.iter().map(|&i| prev[i]).collect();
+            up.push(next);
+        }
+
+        TreeIndex {
+            tree,
+            pre,
+            last,
+            depth,
+            up,
+        }
+    }
+
+    /// Returns the indexed tree.
+    pub fn tree(&self) -> &'a Tree<T> {
+        self.tree
+    }
+
+    fn contains(&self, a: usize, b: usize) -> bool {
+        self.pre[a] <= self.pre[b] && self.pre[b] <= self.last[a]
+    }
+
+    /// Returns true if `a` is an ancestor of `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is not valid.
+    pub fn is_ancestor_of(&self, a: NodeId, b: NodeId) -> bool {
+        a != b && self.contains(a.to_index(), b.to_index())
+    }
+
+    /// Returns the number of nodes in the subtree starting at `id`, including
+    /// the node itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not valid.
+    pub fn subtree_size(&self, id: NodeId) -> usize {
+        let i = id.to_index();
+        self.last[i] - self.pre[i] + 1
+    }
+
+    /// Returns the number of ancestors of `id`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not valid.
+    pub fn depth(&self, id: NodeId) -> usize {
+        self.depth[id.to_index()]
+    }
+
+    /// Returns the deepest node which is an ancestor of, or the same node as,
+    /// both `a` and `b`, or `None` if they do not belong to the same subtree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is not valid.
+    pub fn lowest_common_ancestor(&self, a: NodeId, b: NodeId) -> Option<NodeRef<'a, T>> {
+        let (mut a, b) = (a.to_index(), b.to_index());
+        if !self.contains(a, b) {
+            for up in self.up.iter().rev() {
+                if !self.contains(up[a], b) {
+                    a = up[a];
+                }
+            }
+            // Roots are their own parent, so this fails for disconnected nodes.
+            a = self.up[0][a];
+            if !self.contains(a, b) {
+                return None;
+            }
+        }
+        Some(unsafe { self.tree.get_unchecked(NodeId::from_index(a)) })
+    }
+}
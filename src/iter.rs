@@ -2,7 +2,7 @@ use std::iter::FusedIterator;
 use std::ops::Range;
 use std::{slice, vec};
 
-use crate::{Node, NodeId, NodeRef, Tree};
+use crate::{DocumentPosition, Node, NodeId, NodeRef, Tree};
 
 /// Iterator that moves out of a tree in insert order.
 #[derive(Debug)]
@@ -130,6 +130,32 @@ impl<T> Tree<T> {
             iter: 0..self.vec.len(),
         }
     }
+
+    /// Returns an iterator which traverses the nodes between `start` and `end`
+    /// in document order.
+    ///
+    /// The traversal begins with the open edge of `start` and finishes with
+    /// the close edge of `end`. Nodes partially covered by the range yield
+    /// only one of their edges: ancestors of `start` only their close edge,
+    /// ancestors of `end` only their open edge.
+    ///
+    /// The iterator is empty if the close edge of `end` comes before the open
+    /// edge of `start`, or if the nodes do not belong to the same subtree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` or `end` is not valid.
+    pub fn traverse_range(&self, start: NodeId, end: NodeId) -> TraverseRange<'_, T> {
+        let start = self.get(start).unwrap();
+        let end = self.get(end).unwrap();
+        TraverseRange {
+            edge: match end.compare_document_position(&start) {
+                DocumentPosition::Following | DocumentPosition::Disconnected => None,
+                _ => Some(Edge::Open(start)),
+            },
+            end,
+        }
+    }
 }
 
 macro_rules! axis_iterators {
@@ -171,27 +197,68 @@ axis_iterators! {
 
     /// Iterator over last children.
     LastChildren(NodeRef::last_child);
+
+    /// Iterator over following nodes in document order.
+    Following(NodeRef::next_in_document_order);
+}
+
+/// Iterator over preceding nodes in reverse document order.
+#[derive(Debug)]
+pub struct Preceding<'a, T: 'a> {
+    next: Option<NodeRef<'a, T>>,
+    ancestor: Option<NodeRef<'a, T>>,
+}
+impl<'a, T: 'a> Clone for Preceding<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next,
+            ancestor: self.ancestor,
+        }
+    }
+}
+impl<'a, T: 'a> FusedIterator for Preceding<'a, T> {}
+impl<'a, T: 'a> Iterator for Preceding<'a, T> {
+    type Item = NodeRef<'a, T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let node = self.next.take()?;
+            self.next = node.prev_in_document_order();
+            if Some(node) == self.ancestor {
+                self.ancestor = node.parent();
+            } else {
+                return Some(node);
+            }
+        }
+    }
 }
 
 /// Iterator over children.
+///
+/// Implements `ExactSizeIterator` with the `child-count` feature.
 #[derive(Debug)]
 pub struct Children<'a, T: 'a> {
     front: Option<NodeRef<'a, T>>,
     back: Option<NodeRef<'a, T>>,
+    #[cfg(feature = "child-count")]
+    len: usize,
 }
 impl<'a, T: 'a> Clone for Children<'a, T> {
     fn clone(&self) -> Self {
         Self {
             front: self.front,
             back: self.back,
+            #[cfg(feature = "child-count")]
+            len: self.len,
         }
     }
 }
+#[cfg(feature = "child-count")]
+impl<'a, T: 'a> ExactSizeIterator for Children<'a, T> {}
 impl<'a, T: 'a> FusedIterator for Children<'a, T> {}
 impl<'a, T: 'a> Iterator for Children<'a, T> {
     type Item = NodeRef<'a, T>;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.front == self.back {
+        let node = if self.front == self.back {
             let node = self.front.take();
             self.back = None;
             node
@@ -199,12 +266,21 @@ impl<'a, T: 'a> Iterator for Children<'a, T> {
             let node = self.front.take();
             self.front = node.as_ref().and_then(NodeRef::next_sibling);
             node
+        };
+        #[cfg(feature = "child-count")]
+        if node.is_some() {
+            self.len -= 1;
         }
+        node
+    }
+    #[cfg(feature = "child-count")]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
     }
 }
 impl<'a, T: 'a> DoubleEndedIterator for Children<'a, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.back == self.front {
+        let node = if self.back == self.front {
             let node = self.back.take();
             self.front = None;
             node
@@ -212,7 +288,12 @@ impl<'a, T: 'a> DoubleEndedIterator for Children<'a, T> {
             let node = self.back.take();
             self.back = node.as_ref().and_then(NodeRef::prev_sibling);
             node
+        };
+        #[cfg(feature = "child-count")]
+        if node.is_some() {
+            self.len -= 1;
         }
+        node
     }
 }
 
@@ -240,6 +321,60 @@ impl<'a, T: 'a> PartialEq for Edge<'a, T> {
     }
 }
 
+/// Open or close edge of a mutable node.
+///
+/// Passed to the function given to
+/// [`NodeMut::for_each_edge`](crate::NodeMut::for_each_edge).
+#[derive(Debug)]
+pub enum EdgeMut<'a, T: 'a> {
+    /// Open.
+    Open(EdgeNode<'a, T>),
+    /// Close.
+    Close(EdgeNode<'a, T>),
+}
+
+impl<'a, T: 'a> EdgeMut<'a, T> {
+    /// Returns the node of this edge.
+    pub fn node(&mut self) -> &mut EdgeNode<'a, T> {
+        match self {
+            EdgeMut::Open(node) | EdgeMut::Close(node) => node,
+        }
+    }
+
+    /// Returns the value of the node of this edge.
+    pub fn value(&mut self) -> &mut T {
+        self.node().value()
+    }
+}
+
+/// Node of an [`EdgeMut`].
+///
+/// Gives mutable access to the value of the node, and read access to the
+/// tree, but cannot change the structure of the tree during traversal.
+#[derive(Debug)]
+pub struct EdgeNode<'a, T: 'a> {
+    pub(crate) id: NodeId,
+    pub(crate) tree: &'a mut Tree<T>,
+}
+
+impl<'a, T: 'a> EdgeNode<'a, T> {
+    /// Returns the ID of this node.
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    /// Returns the value of this node.
+    pub fn value(&mut self) -> &mut T {
+        unsafe { &mut self.tree.node_mut(self.id).value }
+    }
+
+    /// Returns a reference to this node, from which the rest of the tree can
+    /// be read.
+    pub fn as_ref(&self) -> NodeRef<'_, T> {
+        unsafe { self.tree.get_unchecked(self.id) }
+    }
+}
+
 /// Iterator which traverses a subtree.
 #[derive(Debug)]
 pub struct Traverse<'a, T: 'a> {
@@ -286,6 +421,40 @@ impl<'a, T: 'a> Iterator for Traverse<'a, T> {
     }
 }
 
+/// Iterator which traverses the nodes between two nodes.
+#[derive(Debug)]
+pub struct TraverseRange<'a, T: 'a> {
+    edge: Option<Edge<'a, T>>,
+    end: NodeRef<'a, T>,
+}
+impl<'a, T: 'a> Clone for TraverseRange<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            edge: self.edge,
+            end: self.end,
+        }
+    }
+}
+impl<'a, T: 'a> FusedIterator for TraverseRange<'a, T> {}
+impl<'a, T: 'a> Iterator for TraverseRange<'a, T> {
+    type Item = Edge<'a, T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let edge = self.edge.take()?;
+        self.edge = match edge {
+            Edge::Close(node) if node == self.end => None,
+            Edge::Open(node) => match node.first_child() {
+                Some(first_child) => Some(Edge::Open(first_child)),
+                None => Some(Edge::Close(node)),
+            },
+            Edge::Close(node) => match node.next_sibling() {
+                Some(next_sibling) => Some(Edge::Open(next_sibling)),
+                None => node.parent().map(Edge::Close),
+            },
+        };
+        Some(edge)
+    }
+}
+
 /// Iterator over a node and its descendants.
 #[derive(Debug)]
 pub struct Descendants<'a, T: 'a>(Traverse<'a, T>);
@@ -307,6 +476,22 @@ impl<'a, T: 'a> Iterator for Descendants<'a, T> {
     }
 }
 
+/// Iterator over the leaves of a subtree.
+#[derive(Debug)]
+pub struct Leaves<'a, T: 'a>(Descendants<'a, T>);
+impl<'a, T: 'a> Clone for Leaves<'a, T> {
+    fn clone(&self) -> Self {
+        Leaves(self.0.clone())
+    }
+}
+impl<'a, T: 'a> FusedIterator for Leaves<'a, T> {}
+impl<'a, T: 'a> Iterator for Leaves<'a, T> {
+    type Item = NodeRef<'a, T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.find(|node| !node.has_children())
+    }
+}
+
 impl<'a, T: 'a> NodeRef<'a, T> {
     /// Returns an iterator over ancestors.
     pub fn ancestors(&self) -> Ancestors<'a, T> {
@@ -333,11 +518,28 @@ impl<'a, T: 'a> NodeRef<'a, T> {
         LastChildren(self.last_child())
     }
 
+    /// Returns an iterator over nodes following this node in document order,
+    /// excluding its descendants.
+    pub fn following(&self) -> Following<'a, T> {
+        Following(self.next_after_subtree())
+    }
+
+    /// Returns an iterator over nodes preceding this node in reverse document
+    /// order, excluding its ancestors.
+    pub fn preceding(&self) -> Preceding<'a, T> {
+        Preceding {
+            next: self.prev_in_document_order(),
+            ancestor: self.parent(),
+        }
+    }
+
     /// Returns an iterator over children.
     pub fn children(&self) -> Children<'a, T> {
         Children {
             front: self.first_child(),
             back: self.last_child(),
+            #[cfg(feature = "child-count")]
+            len: self.node.child_count,
         }
     }
 
@@ -353,4 +555,10 @@ impl<'a, T: 'a> NodeRef<'a, T> {
     pub fn descendants(&self) -> Descendants<'a, T> {
         Descendants(self.traverse())
     }
+
+    /// Returns an iterator over the leaves of the subtree starting at this
+    /// node, that is its descendants without children.
+    pub fn leaves(&self) -> Leaves<'a, T> {
+        Leaves(self.descendants())
+    }
 }
@@ -37,10 +37,16 @@
 
 use std::fmt::{self, Debug, Display, Formatter};
 use std::num::NonZeroUsize;
+use std::str::FromStr;
+
+use crate::iter::{EdgeMut, EdgeNode};
 
 #[cfg(feature = "serde")]
 pub mod serde;
 
+#[cfg(feature = "rayon")]
+pub mod rayon;
+
 /// Vec-backed ID-tree.
 ///
 /// Always contains at least a root node.
@@ -52,7 +58,7 @@ pub struct Tree<T> {
 /// Node ID.
 ///
 /// Index into a `Tree`-internal `Vec`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct NodeId(NonZeroUsize);
 
 impl NodeId {
@@ -66,17 +72,68 @@ impl NodeId {
     fn to_index(self) -> usize {
         self.0.get() - 1
     }
+
+    /// Returns the index of this ID in its tree.
+    ///
+    /// Indices are dense, starting from zero for the root, in insert order.
+    /// They can be turned back into IDs with [`Tree::node_id_from_index`].
+    pub fn index(self) -> usize {
+        self.to_index()
+    }
+}
+
+/// Formats the index of the ID, like `Display`.
+impl Debug for NodeId {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("NodeId").field(&self.to_index()).finish()
+    }
+}
+
+/// Formats the index of the ID.
+impl Display for NodeId {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.to_index(), f)
+    }
+}
+
+/// Parses an ID from its index, as formatted by `Display`.
+///
+/// The ID is not checked against any tree.
+impl FromStr for NodeId {
+    type Err = ParseNodeIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<usize>() {
+            Ok(n) if n != usize::MAX => Ok(unsafe { NodeId::from_index(n) }),
+            _ => Err(ParseNodeIdError(())),
+        }
+    }
+}
+
+/// Error returned when parsing a `NodeId` fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseNodeIdError(());
+
+impl Display for ParseNodeIdError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("invalid node ID")
+    }
 }
 
+impl std::error::Error for ParseNodeIdError {}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct Node<T> {
     parent: Option<NodeId>,
     prev_sibling: Option<NodeId>,
     next_sibling: Option<NodeId>,
     children: Option<(NodeId, NodeId)>,
+    #[cfg(feature = "child-count")]
+    child_count: usize,
     value: T,
 }
 
+#[cfg(not(feature = "child-count"))]
 fn _static_assert_size_of_node() {
     // "Instantiating" the generic `transmute` function without calling it
     // still triggers the magic compile-time check
@@ -84,6 +141,11 @@ fn _static_assert_size_of_node() {
     let _ = std::mem::transmute::<Node<()>, [usize; 5]>;
 }
 
+#[cfg(feature = "child-count")]
+fn _static_assert_size_of_node() {
+    let _ = std::mem::transmute::<Node<()>, [usize; 6]>;
+}
+
 impl<T> Node<T> {
     fn new(value: T) -> Self {
         Node {
@@ -91,10 +153,40 @@ impl<T> Node<T> {
             prev_sibling: None,
             next_sibling: None,
             children: None,
+            #[cfg(feature = "child-count")]
+            child_count: 0,
             value,
         }
     }
 
+    // Child count bookkeeping, which does nothing unless the `child-count`
+    // feature is enabled.
+
+    fn add_child_count(&mut self, _n: usize) {
+        #[cfg(feature = "child-count")]
+        {
+            self.child_count += _n;
+        }
+    }
+
+    fn sub_child_count(&mut self, _n: usize) {
+        #[cfg(feature = "child-count")]
+        {
+            self.child_count -= _n;
+        }
+    }
+
+    fn take_child_count(&mut self) -> usize {
+        #[cfg(feature = "child-count")]
+        {
+            std::mem::take(&mut self.child_count)
+        }
+        #[cfg(not(feature = "child-count"))]
+        {
+            0
+        }
+    }
+
     pub fn map<F, U>(self, mut transform: F) -> Node<U>
     where
         F: FnMut(T) -> U,
@@ -104,6 +196,8 @@ impl<T> Node<T> {
             prev_sibling: self.prev_sibling,
             next_sibling: self.next_sibling,
             children: self.children,
+            #[cfg(feature = "child-count")]
+            child_count: self.child_count,
             value: transform(self.value),
         }
     }
@@ -117,9 +211,26 @@ impl<T> Node<T> {
             prev_sibling: self.prev_sibling,
             next_sibling: self.next_sibling,
             children: self.children,
+            #[cfg(feature = "child-count")]
+            child_count: self.child_count,
             value: transform(&self.value),
         }
     }
+
+    pub fn try_map<F, U, E>(self, mut transform: F) -> Result<Node<U>, E>
+    where
+        F: FnMut(T) -> Result<U, E>,
+    {
+        Ok(Node {
+            parent: self.parent,
+            prev_sibling: self.prev_sibling,
+            next_sibling: self.next_sibling,
+            children: self.children,
+            #[cfg(feature = "child-count")]
+            child_count: self.child_count,
+            value: transform(self.value)?,
+        })
+    }
 }
 
 /// Node reference.
@@ -144,6 +255,34 @@ pub struct NodeMut<'a, T: 'a> {
     tree: &'a mut Tree<T>,
 }
 
+/// Position of a node relative to another.
+///
+/// Returned by [`NodeRef::compare_document_position`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DocumentPosition {
+    /// The nodes are the same.
+    Same,
+    /// The other node precedes this node in document order.
+    Preceding,
+    /// The other node follows this node in document order.
+    Following,
+    /// The other node is an ancestor of this node.
+    Contains,
+    /// The other node is a descendant of this node.
+    ContainedBy,
+    /// The nodes do not belong to the same subtree.
+    Disconnected,
+}
+
+/// How [`Tree::filter`] handles the descendants of removed nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FilterMode {
+    /// Remove the whole subtree of removed nodes.
+    Prune,
+    /// Move the children of removed nodes into the nearest kept ancestor.
+    Hoist,
+}
+
 // Trait implementations regardless of T.
 
 impl<'a, T: 'a> Copy for NodeRef<'a, T> {}
@@ -192,6 +331,11 @@ impl<T> Tree<T> {
         exists.map(move |_| NodeMut { id, tree: self })
     }
 
+    /// Returns the ID of the node at the specified index, if it exists.
+    pub fn node_id_from_index(&self, index: usize) -> Option<NodeId> {
+        (index < self.vec.len()).then(|| unsafe { NodeId::from_index(index) })
+    }
+
     unsafe fn node(&self, id: NodeId) -> &Node<T> {
         unsafe { self.vec.get_unchecked(id.to_index()) }
     }
@@ -261,6 +405,23 @@ impl<T> Tree<T> {
         unsafe { self.get_unchecked_mut(other_tree_root_id) }
     }
 
+    /// Returns the deepest node which is an ancestor of, or the same node as,
+    /// both specified nodes.
+    ///
+    /// Returns `None` if either ID is not valid or if the nodes do not belong
+    /// to the same subtree.
+    pub fn lowest_common_ancestor(&self, a: NodeId, b: NodeId) -> Option<NodeRef<'_, T>> {
+        let a_path = self.get(a)?.path_from_root();
+        let b_path = self.get(b)?.path_from_root();
+        let id = a_path
+            .iter()
+            .zip(&b_path)
+            .take_while(|(a, b)| a == b)
+            .last()?
+            .0;
+        Some(unsafe { self.get_unchecked(*id) })
+    }
+
     /// Maps a `Tree<T>` to `Tree<U>` by applying a function to all node values,
     /// copying over the tree's structure and node ids untouched, consuming `self`.
     pub fn map<F, U>(self, mut transform: F) -> Tree<U>
@@ -290,6 +451,51 @@ impl<T> Tree<T> {
                 .collect(),
         }
     }
+
+    /// Maps a `Tree<T>` to `Tree<U>` by applying a fallible function to all
+    /// node values, copying over the tree's structure and node ids untouched,
+    /// consuming `self`.
+    ///
+    /// Stops at the first error and returns it.
+    pub fn try_map<F, U, E>(self, mut transform: F) -> Result<Tree<U>, E>
+    where
+        F: FnMut(T) -> Result<U, E>,
+    {
+        Ok(Tree {
+            vec: self
+                .vec
+                .into_iter()
+                .map(|node| node.try_map(&mut transform))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Maps a `&Tree<T>` to `Tree<U>` by applying a function to all nodes,
+    /// copying over the tree's structure and node ids untouched.
+    ///
+    /// The function receives a `NodeRef`, giving access to the position of
+    /// the node in the tree alongside its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ego_tree::tree;
+    ///
+    /// let tree = tree!('a' => { 'b' => { 'c' } });
+    /// let depths = tree.map_with_node(|node| (*node.value(), node.depth()));
+    /// assert_eq!(tree!(('a', 0) => { ('b', 1) => { ('c', 2) } }), depths);
+    /// ```
+    pub fn map_with_node<F, U>(&self, mut transform: F) -> Tree<U>
+    where
+        F: FnMut(NodeRef<'_, T>) -> U,
+    {
+        Tree {
+            vec: self
+                .nodes()
+                .map(|node| node.node.map_ref(|_| transform(node)))
+                .collect(),
+        }
+    }
 }
 
 impl<'a, T: 'a> NodeRef<'a, T> {
@@ -349,6 +555,134 @@ impl<'a, T: 'a> NodeRef<'a, T> {
     pub fn has_children(&self) -> bool {
         self.node.children.is_some()
     }
+
+    /// Returns the number of children of this node.
+    ///
+    /// Constant time with the `child-count` feature, linear otherwise.
+    pub fn child_count(&self) -> usize {
+        #[cfg(feature = "child-count")]
+        {
+            self.node.child_count
+        }
+        #[cfg(not(feature = "child-count"))]
+        {
+            self.children().count()
+        }
+    }
+
+    /// Returns the child of this node at position `n`.
+    ///
+    /// With the `child-count` feature, returns early when `n` is out of
+    /// bounds and walks from the last child when `n` is in the second half.
+    pub fn nth_child(&self, n: usize) -> Option<Self> {
+        #[cfg(feature = "child-count")]
+        {
+            let count = self.node.child_count;
+            if n >= count {
+                return None;
+            }
+            if n > count / 2 {
+                return self.children().nth_back(count - 1 - n);
+            }
+        }
+        self.children().nth(n)
+    }
+
+    /// Returns the number of ancestors of this node.
+    pub fn depth(&self) -> usize {
+        self.ancestors().count()
+    }
+
+    /// Returns the IDs of the ancestors of this node and of this node itself,
+    /// starting from the root.
+    pub fn path_from_root(&self) -> Vec<NodeId> {
+        let mut path = vec![self.id];
+        path.extend(self.ancestors().map(|node| node.id));
+        path.reverse();
+        path
+    }
+
+    /// Returns the position of this node among the children of its parent,
+    /// or `None` if it has no parent.
+    pub fn index_in_parent(&self) -> Option<usize> {
+        self.node.parent?;
+        Some(self.prev_siblings().count())
+    }
+
+    /// Returns true if this node is an ancestor of `other`.
+    pub fn is_ancestor_of(&self, other: &Self) -> bool {
+        other.ancestors().any(|node| node == *self)
+    }
+
+    /// Returns true if this node is a descendant of `other`.
+    pub fn is_descendant_of(&self, other: &Self) -> bool {
+        other.is_ancestor_of(self)
+    }
+
+    /// Returns the position of `other` relative to this node.
+    pub fn compare_document_position(&self, other: &Self) -> DocumentPosition {
+        if self == other {
+            return DocumentPosition::Same;
+        }
+        if !std::ptr::eq(self.tree, other.tree) {
+            return DocumentPosition::Disconnected;
+        }
+
+        let self_path = self.path_from_root();
+        let other_path = other.path_from_root();
+        if self_path[0] != other_path[0] {
+            return DocumentPosition::Disconnected;
+        }
+
+        let i = self_path
+            .iter()
+            .zip(&other_path)
+            .take_while(|(a, b)| a == b)
+            .count();
+        if i == self_path.len() {
+            return DocumentPosition::ContainedBy;
+        }
+        if i == other_path.len() {
+            return DocumentPosition::Contains;
+        }
+
+        let branch = unsafe { self.tree.get_unchecked(self_path[i]) };
+        if branch.next_siblings().any(|node| node.id == other_path[i]) {
+            DocumentPosition::Following
+        } else {
+            DocumentPosition::Preceding
+        }
+    }
+
+    /// Returns the node following this one in document order.
+    ///
+    /// This is the first child of this node if it has one, otherwise the
+    /// next sibling of this node or of its nearest ancestor having one.
+    pub fn next_in_document_order(&self) -> Option<Self> {
+        self.first_child().or_else(|| self.next_after_subtree())
+    }
+
+    /// Returns the node preceding this one in document order.
+    ///
+    /// This is the last descendant of the previous sibling of this node if it
+    /// has one, otherwise the parent of this node.
+    pub fn prev_in_document_order(&self) -> Option<Self> {
+        match self.prev_sibling() {
+            Some(prev_sibling) => prev_sibling.last_children().last().or(Some(prev_sibling)),
+            None => self.parent(),
+        }
+    }
+
+    /// Returns the first node after the subtree of this node in document order.
+    fn next_after_subtree(&self) -> Option<Self> {
+        let mut node = *self;
+        loop {
+            if let Some(next_sibling) = node.next_sibling() {
+                return Some(next_sibling);
+            }
+            node = node.parent()?;
+        }
+    }
 }
 
 impl<'a, T: 'a> NodeMut<'a, T> {
@@ -580,6 +914,65 @@ impl<'a, T: 'a> NodeMut<'a, T> {
         }
     }
 
+    /// Apply function for the open and close edge of this node and each
+    /// descendant, in the same order as [`NodeRef::traverse`].
+    ///
+    /// A node's close edge is visited after those of all its descendants,
+    /// so values computed from its children can be stored into it in a
+    /// single pass. The function can change values but not the structure of
+    /// the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ego_tree::{iter::EdgeMut, tree};
+    ///
+    /// // Replace each value by the sum of its subtree.
+    /// let mut tree = tree!(1 => { 2 => { 3, 4 }, 5 });
+    /// tree.root_mut().for_each_edge(|edge| {
+    ///     if let EdgeMut::Close(mut node) = edge {
+    ///         let sum: i32 = node.as_ref().children().map(|c| *c.value()).sum();
+    ///         *node.value() += sum;
+    ///     }
+    /// });
+    /// assert_eq!(tree, tree!(15 => { 9 => { 3, 4 }, 5 }));
+    /// ```
+    pub fn for_each_edge<F>(&mut self, mut f: F)
+    where
+        F: FnMut(EdgeMut<'_, T>),
+    {
+        let root = self.id;
+        let mut id = root;
+        let mut open = true;
+
+        loop {
+            let node = EdgeNode {
+                id,
+                tree: &mut *self.tree,
+            };
+            if open {
+                f(EdgeMut::Open(node));
+                match unsafe { self.tree.node(id) }.children {
+                    Some((first_child, _)) => id = first_child,
+                    None => open = false,
+                }
+            } else {
+                f(EdgeMut::Close(node));
+                if id == root {
+                    return;
+                }
+                let node = unsafe { self.tree.node(id) };
+                match node.next_sibling {
+                    Some(next_sibling) => {
+                        id = next_sibling;
+                        open = true;
+                    }
+                    None => id = node.parent.unwrap(),
+                }
+            }
+        }
+    }
+
     /// Appends a new child to this node.
     pub fn append(&mut self, value: T) -> NodeMut<'_, T> {
         let id = self.tree.orphan(value).id;
@@ -651,6 +1044,7 @@ impl<'a, T: 'a> NodeMut<'a, T> {
         }
 
         let parent = unsafe { self.tree.node_mut(parent_id) };
+        parent.sub_child_count(1);
         let (first_child_id, last_child_id) = parent.children.unwrap();
         if first_child_id == last_child_id {
             parent.children = None;
@@ -694,6 +1088,7 @@ impl<'a, T: 'a> NodeMut<'a, T> {
                     Some((first_child_id, _)) => Some((first_child_id, new_child_id)),
                     None => Some((new_child_id, new_child_id)),
                 };
+                self.node().add_child_count(1);
             }
         }
 
@@ -733,6 +1128,7 @@ impl<'a, T: 'a> NodeMut<'a, T> {
                     Some((_, last_child_id)) => Some((new_child_id, last_child_id)),
                     None => Some((new_child_id, new_child_id)),
                 };
+                self.node().add_child_count(1);
             }
         }
 
@@ -773,6 +1169,7 @@ impl<'a, T: 'a> NodeMut<'a, T> {
 
         {
             let parent = unsafe { self.tree.node_mut(parent_id) };
+            parent.add_child_count(1);
             let (first_child_id, last_child_id) = parent.children.unwrap();
             if first_child_id == self.id {
                 parent.children = Some((new_sibling_id, last_child_id));
@@ -816,6 +1213,7 @@ impl<'a, T: 'a> NodeMut<'a, T> {
 
         {
             let parent = unsafe { self.tree.node_mut(parent_id) };
+            parent.add_child_count(1);
             let (first_child_id, last_child_id) = parent.children.unwrap();
             if last_child_id == self.id {
                 parent.children = Some((first_child_id, new_sibling_id));
@@ -837,13 +1235,14 @@ impl<'a, T: 'a> NodeMut<'a, T> {
             "Cannot reparent node's children to itself"
         );
 
-        let new_child_ids = {
+        let (new_child_ids, new_child_count) = {
             let mut from = self.tree.get_mut(from_id).unwrap();
             match from.node().children.take() {
-                Some(ids) => ids,
+                Some(ids) => (ids, from.node().take_child_count()),
                 None => return,
             }
         };
+        self.node().add_child_count(new_child_count);
 
         let mut child_id = new_child_ids.0;
         loop {
@@ -881,13 +1280,14 @@ impl<'a, T: 'a> NodeMut<'a, T> {
             "Cannot reparent node's children to itself"
         );
 
-        let new_child_ids = {
+        let (new_child_ids, new_child_count) = {
             let mut from = self.tree.get_mut(from_id).unwrap();
             match from.node().children.take() {
-                Some(ids) => ids,
+                Some(ids) => (ids, from.node().take_child_count()),
                 None => return,
             }
         };
+        self.node().add_child_count(new_child_count);
 
         let mut child_id = new_child_ids.0;
         loop {
@@ -1009,6 +1409,22 @@ impl<'a, T: 'a> From<NodeMut<'a, T>> for NodeRef<'a, T> {
 /// Iterators.
 pub mod iter;
 
+pub mod index;
+
+pub mod summary;
+
+pub mod node_map;
+
+pub mod print;
+
+pub mod dot;
+
+pub mod outline;
+
+pub mod format;
+
+pub mod builder;
+
 /// Creates a tree from expressions.
 ///
 /// # Examples
@@ -1051,10 +1467,131 @@ pub mod iter;
 /// };
 /// # }
 /// ```
+///
+/// Splice children from an iterator of values with `#..`, or of subtrees
+/// with `@ ..`:
+/// ```
+/// #[macro_use] extern crate ego_tree;
+/// # fn main() {
+/// let subtrees = vec![tree!(3 => { 4 }), tree!(5)];
+/// let tree = tree! {
+///     0 => {
+///         #..1..3,
+///         @ ..subtrees,
+///     }
+/// };
+/// assert_eq!(tree!(0 => { 1, 2, 3 => { 4 }, 5 }), tree);
+/// # }
+/// ```
+///
+/// Include a child only if a condition holds with `#if`:
+/// ```
+/// #[macro_use] extern crate ego_tree;
+/// # fn main() {
+/// let verbose = false;
+/// let tree = tree! {
+///     "root" => {
+///         #if verbose => "details" => { "a", "b" },
+///         #if !verbose => "summary",
+///     }
+/// };
+/// assert_eq!(tree!("root" => { "summary" }), tree);
+/// # }
+/// ```
+///
+/// Bind the ID of a node to a variable declared beforehand with `id @`, as in
+/// a pattern binding. A variable bound by a conditional child must be
+/// initialized beforehand:
+/// ```
+/// #[macro_use] extern crate ego_tree;
+/// # fn main() {
+/// let (root, b);
+/// let tree = tree! {
+///     root @ "root" => {
+///         "a",
+///         b @ "b".trim() => { "c" },
+///     }
+/// };
+/// assert_eq!(tree.root().id(), root);
+/// assert_eq!(&"b", tree.get(b).unwrap().value());
+/// # }
+/// ```
 #[macro_export]
 macro_rules! tree {
     (@ $n:ident { }) => { };
 
+    // Conditional node with bound ID and children.
+    (@ $n:ident { #if $cond:expr_2021 => $id:ident @ $value:expr_2021 => $children:tt $(, $($tail:tt)*)? }) => {{
+        if $cond {
+            tree!(@ $n { $id @ $value => $children });
+        }
+        $( tree!(@ $n { $($tail)* }); )?
+    }};
+
+    // Conditional node with bound ID.
+    (@ $n:ident { #if $cond:expr_2021 => $id:ident @ $value:expr_2021 $(, $($tail:tt)*)? }) => {{
+        if $cond {
+            tree!(@ $n { $id @ $value });
+        }
+        $( tree!(@ $n { $($tail)* }); )?
+    }};
+
+    // Conditional subtree.
+    (@ $n:ident { #if $cond:expr_2021 => @ $subtree:expr_2021 $(, $($tail:tt)*)? }) => {{
+        if $cond {
+            $n.append_subtree($subtree);
+        }
+        $( tree!(@ $n { $($tail)* }); )?
+    }};
+
+    // Conditional node with children.
+    (@ $n:ident { #if $cond:expr_2021 => $value:expr_2021 => $children:tt $(, $($tail:tt)*)? }) => {{
+        if $cond {
+            tree!(@ $n { $value => $children });
+        }
+        $( tree!(@ $n { $($tail)* }); )?
+    }};
+
+    // Conditional leaf.
+    (@ $n:ident { #if $cond:expr_2021 => $value:expr_2021 $(, $($tail:tt)*)? }) => {{
+        if $cond {
+            $n.append($value);
+        }
+        $( tree!(@ $n { $($tail)* }); )?
+    }};
+
+    // Node with bound ID and children.
+    (@ $n:ident { $id:ident @ $value:expr_2021 => $children:tt $(, $($tail:tt)*)? }) => {{
+        {
+            let mut node = $n.append($value);
+            $id = node.id();
+            tree!(@ node $children);
+        }
+        $( tree!(@ $n { $($tail)* }); )?
+    }};
+
+    // Leaf with bound ID.
+    (@ $n:ident { $id:ident @ $value:expr_2021 $(, $($tail:tt)*)? }) => {{
+        $id = $n.append($value).id();
+        $( tree!(@ $n { $($tail)* }); )?
+    }};
+
+    // Leaves from an iterator of values.
+    (@ $n:ident { #..$iter:expr_2021 $(, $($tail:tt)*)? }) => {{
+        for value in $iter {
+            $n.append(value);
+        }
+        $( tree!(@ $n { $($tail)* }); )?
+    }};
+
+    // Subtrees from an iterator of trees.
+    (@ $n:ident { @ ..$iter:expr_2021 $(, $($tail:tt)*)? }) => {{
+        for subtree in $iter {
+            $n.append_subtree(subtree);
+        }
+        $( tree!(@ $n { $($tail)* }); )?
+    }};
+
     // Last leaf.
     (@ $n:ident { $value:expr_2021 }) => {
         { $n.append($value); }
@@ -1094,6 +1631,18 @@ macro_rules! tree {
     }};
 
 
+    ($id:ident @ $root:expr_2021) => {{
+        let tree = $crate::Tree::new($root);
+        $id = tree.root().id();
+        tree
+    }};
+
+    ($id:ident @ $root:expr_2021 => $children:tt) => {{
+        let tree = tree!($root => $children);
+        $id = tree.root().id();
+        tree
+    }};
+
     ($root:expr_2021) => { $crate::Tree::new($root) };
 
     ($root:expr_2021 => $children:tt) => {
@@ -1146,30 +1695,18 @@ mod display;
 
 impl<T: Display> Display for Tree<T> {
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
-        use crate::display::Indentation;
-        use crate::iter::Edge;
-
-        let mut indent: Indentation = Indentation::new(true);
-
-        for edge in self.root().traverse() {
-            match edge {
-                Edge::Open(node) if node.has_children() => {
-                    indent.indent(node.next_sibling().is_some());
-                    writeln!(f, "{indent}{}", node.value())?;
-                }
-                Edge::Open(node) => {
-                    indent.indent(node.next_sibling().is_some());
-                    writeln!(f, "{indent}{}", node.value())?;
-                    indent.deindent();
-                }
-                Edge::Close(node) if node.has_children() => {
-                    indent.deindent();
-                }
-                _ => {}
-            }
-        }
-        Ok(())
+        Display::fmt(&print::TreePrinter::new(self.root()), f)
     }
 }
 
 mod sort;
+
+mod filter;
+
+mod fold;
+
+mod find;
+
+mod tree_macro;
+
+mod arrays;
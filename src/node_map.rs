@@ -0,0 +1,531 @@
+//! Dense secondary storage keyed by `NodeId`.
+//!
+//! [`NodeMap`] and [`NodeSet`] are backed by a `Vec` indexed by node ID, so
+//! lookups are constant time and storage grows as needed when nodes are added
+//! to the tree. They only store IDs' indices, so they should be used with IDs
+//! from a single tree.
+
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::iter::{Enumerate, FusedIterator};
+use std::ops::{Index, IndexMut};
+use std::{slice, vec};
+
+use crate::NodeId;
+
+/// Map from `NodeId` to values, backed by a `Vec`.
+///
+/// # Examples
+///
+/// ```
+/// use ego_tree::{node_map::NodeMap, tree};
+///
+/// let tree = tree!('a' => { 'b', 'c' });
+/// let mut depths = NodeMap::new();
+/// for node in tree.root().descendants() {
+///     depths.insert(node.id(), node.ancestors().count());
+/// }
+/// assert_eq!(1, depths[tree.root().last_child().unwrap().id()]);
+/// ```
+#[derive(Clone)]
+pub struct NodeMap<V> {
+    slots: Vec<Option<V>>,
+    len: usize,
+}
+
+impl<V> Default for NodeMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Implemented over entries, since maps with the same entries may have
+// different numbers of trailing empty slots.
+
+impl<V: PartialEq> PartialEq for NodeMap<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<V: Eq> Eq for NodeMap<V> {}
+
+impl<V: Hash> Hash for NodeMap<V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for entry in self {
+            entry.hash(state);
+        }
+    }
+}
+
+impl<V: Debug> Debug for NodeMap<V> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<V> NodeMap<V> {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        NodeMap {
+            slots: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Creates an empty map with room for IDs of the first `capacity` nodes
+    /// of a tree.
+    pub fn with_capacity(capacity: usize) -> Self {
+        NodeMap {
+            slots: Vec::with_capacity(capacity),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all entries from the map.
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.len = 0;
+    }
+
+    /// Returns true if the map has an entry for `id`.
+    pub fn contains_key(&self, id: NodeId) -> bool {
+        self.get(id).is_some()
+    }
+
+    /// Returns a reference to the value of `id`.
+    pub fn get(&self, id: NodeId) -> Option<&V> {
+        self.slots.get(id.to_index()).and_then(Option::as_ref)
+    }
+
+    /// Returns a mutable reference to the value of `id`.
+    pub fn get_mut(&mut self, id: NodeId) -> Option<&mut V> {
+        self.slots.get_mut(id.to_index()).and_then(Option::as_mut)
+    }
+
+    fn slot(&mut self, id: NodeId) -> &mut Option<V> {
+        let index = id.to_index();
+        if index >= self.slots.len() {
+            self.slots.resize_with(index + 1, || None);
+        }
+        &mut self.slots[index]
+    }
+
+    /// Inserts a value for `id`, returning the previous value.
+    pub fn insert(&mut self, id: NodeId, value: V) -> Option<V> {
+        let old = self.slot(id).replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    /// Removes the value of `id`, returning it.
+    pub fn remove(&mut self, id: NodeId) -> Option<V> {
+        let old = self.slots.get_mut(id.to_index()).and_then(Option::take);
+        if old.is_some() {
+            self.len -= 1;
+        }
+        old
+    }
+
+    /// Returns the entry of `id` for in-place manipulation.
+    pub fn entry(&mut self, id: NodeId) -> Entry<'_, V> {
+        if self.contains_key(id) {
+            Entry::Occupied(OccupiedEntry { map: self, id })
+        } else {
+            Entry::Vacant(VacantEntry { map: self, id })
+        }
+    }
+
+    /// Returns an iterator over entries in ID order.
+    pub fn iter(&self) -> Iter<'_, V> {
+        Iter {
+            iter: self.slots.iter().enumerate(),
+            len: self.len,
+        }
+    }
+
+    /// Returns a mutable iterator over entries in ID order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, V> {
+        IterMut {
+            iter: self.slots.iter_mut().enumerate(),
+            len: self.len,
+        }
+    }
+
+    /// Returns an iterator over IDs in order.
+    pub fn keys(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.iter().map(|(id, _)| id)
+    }
+
+    /// Returns an iterator over values in ID order.
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Returns a mutable iterator over values in ID order.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> + '_ {
+        self.iter_mut().map(|(_, value)| value)
+    }
+}
+
+impl<V> Index<NodeId> for NodeMap<V> {
+    type Output = V;
+    fn index(&self, id: NodeId) -> &V {
+        self.get(id).expect("no entry for node ID")
+    }
+}
+
+impl<V> IndexMut<NodeId> for NodeMap<V> {
+    fn index_mut(&mut self, id: NodeId) -> &mut V {
+        self.get_mut(id).expect("no entry for node ID")
+    }
+}
+
+impl<V> FromIterator<(NodeId, V)> for NodeMap<V> {
+    fn from_iter<I: IntoIterator<Item = (NodeId, V)>>(iter: I) -> Self {
+        let mut map = NodeMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<V> Extend<(NodeId, V)> for NodeMap<V> {
+    fn extend<I: IntoIterator<Item = (NodeId, V)>>(&mut self, iter: I) {
+        for (id, value) in iter {
+            self.insert(id, value);
+        }
+    }
+}
+
+/// Entry of a `NodeMap`.
+#[derive(Debug)]
+pub enum Entry<'a, V: 'a> {
+    /// Occupied.
+    Occupied(OccupiedEntry<'a, V>),
+    /// Vacant.
+    Vacant(VacantEntry<'a, V>),
+}
+
+impl<'a, V: 'a> Entry<'a, V> {
+    /// Returns the ID of this entry.
+    pub fn key(&self) -> NodeId {
+        match self {
+            Entry::Occupied(entry) => entry.id,
+            Entry::Vacant(entry) => entry.id,
+        }
+    }
+
+    /// Inserts `default` if vacant, and returns a mutable reference to the
+    /// value.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Inserts the result of `default` if vacant, and returns a mutable
+    /// reference to the value.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Inserts the default value if vacant, and returns a mutable reference
+    /// to the value.
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Applies a function to the value if occupied.
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+/// Occupied entry of a `NodeMap`.
+#[derive(Debug)]
+pub struct OccupiedEntry<'a, V: 'a> {
+    map: &'a mut NodeMap<V>,
+    id: NodeId,
+}
+
+impl<'a, V: 'a> OccupiedEntry<'a, V> {
+    /// Returns the ID of this entry.
+    pub fn key(&self) -> NodeId {
+        self.id
+    }
+
+    /// Returns a reference to the value.
+    pub fn get(&self) -> &V {
+        &self.map[self.id]
+    }
+
+    /// Returns a mutable reference to the value.
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.map[self.id]
+    }
+
+    /// Returns a mutable reference to the value with the lifetime of the map.
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.map[self.id]
+    }
+
+    /// Replaces the value, returning the old one.
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes the entry, returning its value.
+    pub fn remove(self) -> V {
+        self.map.remove(self.id).unwrap()
+    }
+}
+
+/// Vacant entry of a `NodeMap`.
+#[derive(Debug)]
+pub struct VacantEntry<'a, V: 'a> {
+    map: &'a mut NodeMap<V>,
+    id: NodeId,
+}
+
+impl<'a, V: 'a> VacantEntry<'a, V> {
+    /// Returns the ID of this entry.
+    pub fn key(&self) -> NodeId {
+        self.id
+    }
+
+    /// Inserts a value, returning a mutable reference to it.
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.map.len += 1;
+        self.map.slot(self.id).insert(value)
+    }
+}
+
+/// Iterator over the entries of a `NodeMap`.
+#[derive(Debug)]
+pub struct Iter<'a, V: 'a> {
+    iter: Enumerate<slice::Iter<'a, Option<V>>>,
+    len: usize,
+}
+impl<'a, V: 'a> Clone for Iter<'a, V> {
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            len: self.len,
+        }
+    }
+}
+impl<'a, V: 'a> ExactSizeIterator for Iter<'a, V> {}
+impl<'a, V: 'a> FusedIterator for Iter<'a, V> {}
+impl<'a, V: 'a> Iterator for Iter<'a, V> {
+    type Item = (NodeId, &'a V);
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, slot) in &mut self.iter {
+            if let Some(value) = slot {
+                self.len -= 1;
+                return Some((unsafe { NodeId::from_index(index) }, value));
+            }
+        }
+        None
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+/// Mutable iterator over the entries of a `NodeMap`.
+#[derive(Debug)]
+pub struct IterMut<'a, V: 'a> {
+    iter: Enumerate<slice::IterMut<'a, Option<V>>>,
+    len: usize,
+}
+impl<'a, V: 'a> ExactSizeIterator for IterMut<'a, V> {}
+impl<'a, V: 'a> FusedIterator for IterMut<'a, V> {}
+impl<'a, V: 'a> Iterator for IterMut<'a, V> {
+    type Item = (NodeId, &'a mut V);
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, slot) in &mut self.iter {
+            if let Some(value) = slot {
+                self.len -= 1;
+                return Some((unsafe { NodeId::from_index(index) }, value));
+            }
+        }
+        None
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+/// Iterator that moves out of a `NodeMap`.
+#[derive(Debug)]
+pub struct IntoIter<V> {
+    iter: Enumerate<vec::IntoIter<Option<V>>>,
+    len: usize,
+}
+impl<V> ExactSizeIterator for IntoIter<V> {}
+impl<V> FusedIterator for IntoIter<V> {}
+impl<V> Iterator for IntoIter<V> {
+    type Item = (NodeId, V);
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, slot) in &mut self.iter {
+            if let Some(value) = slot {
+                self.len -= 1;
+                return Some((unsafe { NodeId::from_index(index) }, value));
+            }
+        }
+        None
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<V> IntoIterator for NodeMap<V> {
+    type Item = (NodeId, V);
+    type IntoIter = IntoIter<V>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            iter: self.slots.into_iter().enumerate(),
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, V: 'a> IntoIterator for &'a NodeMap<V> {
+    type Item = (NodeId, &'a V);
+    type IntoIter = Iter<'a, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, V: 'a> IntoIterator for &'a mut NodeMap<V> {
+    type Item = (NodeId, &'a mut V);
+    type IntoIter = IterMut<'a, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Set of `NodeId`s, backed by a `Vec`.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct NodeSet {
+    map: NodeMap<()>,
+}
+
+impl Debug for NodeSet {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl NodeSet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        NodeSet {
+            map: NodeMap::new(),
+        }
+    }
+
+    /// Creates an empty set with room for IDs of the first `capacity` nodes
+    /// of a tree.
+    pub fn with_capacity(capacity: usize) -> Self {
+        NodeSet {
+            map: NodeMap::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of IDs in the set.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Removes all IDs from the set.
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Returns true if the set contains `id`.
+    pub fn contains(&self, id: NodeId) -> bool {
+        self.map.contains_key(id)
+    }
+
+    /// Adds `id` to the set, returning true if it was not present.
+    pub fn insert(&mut self, id: NodeId) -> bool {
+        self.map.insert(id, ()).is_none()
+    }
+
+    /// Removes `id` from the set, returning true if it was present.
+    pub fn remove(&mut self, id: NodeId) -> bool {
+        self.map.remove(id).is_some()
+    }
+
+    /// Returns an iterator over IDs in order.
+    pub fn iter(&self) -> SetIter<'_> {
+        SetIter(self.map.iter())
+    }
+}
+
+impl FromIterator<NodeId> for NodeSet {
+    fn from_iter<I: IntoIterator<Item = NodeId>>(iter: I) -> Self {
+        let mut set = NodeSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<NodeId> for NodeSet {
+    fn extend<I: IntoIterator<Item = NodeId>>(&mut self, iter: I) {
+        for id in iter {
+            self.insert(id);
+        }
+    }
+}
+
+/// Iterator over the IDs of a `NodeSet`.
+#[derive(Debug, Clone)]
+pub struct SetIter<'a>(Iter<'a, ()>);
+impl<'a> ExactSizeIterator for SetIter<'a> {}
+impl<'a> FusedIterator for SetIter<'a> {}
+impl<'a> Iterator for SetIter<'a> {
+    type Item = NodeId;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(id, _)| id)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a> IntoIterator for &'a NodeSet {
+    type Item = NodeId;
+    type IntoIter = SetIter<'a>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
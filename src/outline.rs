@@ -0,0 +1,178 @@
+//! Parsing of outlines.
+//!
+//! Reads trees back from the box-drawing outline written by the `Display`
+//! implementation of [`Tree`], or from plain outlines indented with spaces or
+//! tabs, one node per line:
+//!
+//! ```text
+//! root
+//! ├── a
+//! │   └── child 1
+//! └── b
+//! ```
+//!
+//! ```text
+//! root
+//!   a
+//!     child 1
+//!   b
+//! ```
+//!
+//! A node is a child of the nearest preceding line with less indentation.
+//! The first line is the root, even if blank; later blank lines are ignored.
+//! Leading whitespace is part of the indentation, so it is not kept in
+//! labels.
+
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::{NodeId, Tree};
+
+/// Kind of error encountered while parsing an outline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutlineErrorKind<E> {
+    /// The outline has no lines.
+    Empty,
+    /// The first line is indented.
+    IndentedRoot,
+    /// A line other than the first is not indented.
+    MultipleRoots,
+    /// A line is less indented than the previous one, but does not line up
+    /// with any of its ancestors.
+    Indentation,
+    /// The value of a line failed to parse.
+    Value(E),
+}
+
+/// Error encountered while parsing an outline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineError<E> {
+    /// Line of the error, starting from 1.
+    pub line: usize,
+    /// Column of the error in characters, starting from 1.
+    pub column: usize,
+    /// Kind of the error.
+    pub kind: OutlineErrorKind<E>,
+}
+
+impl<E: Display> Display for OutlineError<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        match &self.kind {
+            OutlineErrorKind::Empty => f.write_str("empty outline"),
+            OutlineErrorKind::IndentedRoot => f.write_str("indented root"),
+            OutlineErrorKind::MultipleRoots => f.write_str("multiple roots"),
+            OutlineErrorKind::Indentation => f.write_str("inconsistent indentation"),
+            OutlineErrorKind::Value(error) => write!(f, "invalid value: {error}"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for OutlineError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            OutlineErrorKind::Value(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+const GLYPHS: [&str; 4] = ["│   ", "    ", "├── ", "└── "];
+
+/// Splits a line into its indentation width, in characters, and its label.
+fn split_indent(line: &str) -> (usize, &str) {
+    let mut rest = line;
+    let mut width = 0;
+    while let Some(glyph) = GLYPHS.iter().find(|glyph| rest.starts_with(*glyph)) {
+        rest = &rest[glyph.len()..];
+        width += 4;
+    }
+    let label = rest.trim_start_matches([' ', '\t']);
+    width += rest.len() - label.len();
+    (width, label)
+}
+
+impl<T: FromStr> Tree<T> {
+    /// Parses an outline, parsing each node value with `FromStr`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ego_tree::{Tree, tree};
+    ///
+    /// let tree = Tree::<i32>::parse_outline("1\n  2\n    3\n  4\n").unwrap();
+    /// assert_eq!(tree!(1 => { 2 => { 3 }, 4 }), tree);
+    /// ```
+    pub fn parse_outline(s: &str) -> Result<Self, OutlineError<T::Err>> {
+        let error = |line, column, kind| OutlineError { line, column, kind };
+        let parse = |line, width, label: &str| {
+            label
+                .parse::<T>()
+                .map_err(|e| error(line, width + 1, OutlineErrorKind::Value(e)))
+        };
+
+        let mut lines = s.lines().enumerate().map(|(i, line)| (i + 1, line));
+
+        // The first line is the root, even if blank, as the `Display` output
+        // of a tree with an empty root value starts with an empty line.
+        let (line, text) = lines
+            .next()
+            .ok_or_else(|| error(1, 1, OutlineErrorKind::Empty))?;
+        let (width, label) = split_indent(text);
+        let lines = lines
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                let (width, label) = split_indent(line);
+                (i, width, label)
+            });
+        if width > 0 {
+            return Err(error(line, 1, OutlineErrorKind::IndentedRoot));
+        }
+
+        let mut tree = Tree::new(parse(line, width, label)?);
+        // Indentation widths and IDs of the ancestors of the next line.
+        let mut stack: Vec<(usize, NodeId)> = vec![(0, tree.root().id)];
+
+        for (line, width, label) in lines {
+            if width == 0 {
+                return Err(error(line, 1, OutlineErrorKind::MultipleRoots));
+            }
+            // Close the nodes more indented than this line.
+            let depth = stack.len();
+            while stack.last().unwrap().0 > width {
+                stack.pop();
+            }
+            if stack.last().unwrap().0 == width {
+                stack.pop();
+            } else if stack.len() < depth {
+                return Err(error(line, width + 1, OutlineErrorKind::Indentation));
+            }
+
+            let value = parse(line, width, label)?;
+            let &(_, parent) = stack.last().unwrap();
+            let id = unsafe { tree.get_unchecked_mut(parent) }.append(value).id;
+            stack.push((width, id));
+        }
+
+        Ok(tree)
+    }
+}
+
+impl Tree<String> {
+    /// Parses an outline of strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ego_tree::{Tree, tree};
+    ///
+    /// let tree = tree!("root" => { "a" => { "child 1" }, "b" });
+    /// let parsed = Tree::from_outline(&tree.to_string()).unwrap();
+    /// assert_eq!(tree.map(String::from), parsed);
+    /// ```
+    pub fn from_outline(s: &str) -> Result<Self, OutlineError<Infallible>> {
+        Self::parse_outline(s)
+    }
+}
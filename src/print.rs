@@ -0,0 +1,306 @@
+//! Configurable tree printing.
+//!
+//! [`TreePrinter`] draws any subtree as an outline, like the `Display`
+//! implementation of [`Tree`], with configurable glyphs, node rendering and
+//! elision of deep or wide subtrees. [`DebugStructure`] uses it to show the
+//! IDs and orphans of a tree.
+
+use std::fmt::{self, Debug, Display, Formatter};
+
+use crate::display::Indentation;
+use crate::{NodeRef, Tree};
+
+/// Glyphs used to draw the branches of a tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Glyphs<'g> {
+    /// Prefix of a node followed by siblings.
+    pub branch: &'g str,
+    /// Prefix of the last node among its siblings.
+    pub last_branch: &'g str,
+    /// Indentation below a node followed by siblings.
+    pub vertical: &'g str,
+    /// Indentation below the last node among its siblings.
+    pub space: &'g str,
+    /// Marker of elided nodes.
+    pub ellipsis: &'g str,
+}
+
+impl Glyphs<'static> {
+    /// Unicode box-drawing glyphs, as used by `Display`.
+    pub const UNICODE: Self = Glyphs {
+        branch: "├── ",
+        last_branch: "└── ",
+        vertical: "│   ",
+        space: "    ",
+        ellipsis: "…",
+    };
+
+    /// ASCII glyphs.
+    pub const ASCII: Self = Glyphs {
+        branch: "|-- ",
+        last_branch: "`-- ",
+        vertical: "|   ",
+        space: "    ",
+        ellipsis: "...",
+    };
+}
+
+impl Default for Glyphs<'static> {
+    fn default() -> Self {
+        Glyphs::UNICODE
+    }
+}
+
+type Render<'a, T> = Box<dyn Fn(&mut Formatter, NodeRef<'a, T>) -> fmt::Result + 'a>;
+
+/// Builder printing a subtree as an outline.
+///
+/// # Examples
+///
+/// ```
+/// use ego_tree::print::{Glyphs, TreePrinter};
+/// use ego_tree::tree;
+///
+/// let tree = tree!("root" => { "a" => { "b", "c" }, "d", "e", "f" });
+/// let printer = TreePrinter::new(tree.root())
+///     .glyphs(Glyphs::ASCII)
+///     .max_children(2)
+///     .render(|f, node| write!(f, "<{}>", node.value()));
+/// assert_eq!(
+///     printer.to_string(),
+///     "<root>\n|-- <a>\n|   |-- <b>\n|   `-- <c>\n|-- <d>\n`-- ... 2 more\n",
+/// );
+/// ```
+pub struct TreePrinter<'a, 'g, T: 'a> {
+    node: NodeRef<'a, T>,
+    glyphs: Glyphs<'g>,
+    render: Render<'a, T>,
+    indent_root: bool,
+    max_depth: Option<usize>,
+    max_children: Option<usize>,
+}
+
+impl<'a, T: 'a> Debug for TreePrinter<'a, '_, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TreePrinter")
+            .field("node", &self.node.id())
+            .field("glyphs", &self.glyphs)
+            .field("indent_root", &self.indent_root)
+            .field("max_depth", &self.max_depth)
+            .field("max_children", &self.max_children)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T: Display + 'a> TreePrinter<'a, 'static, T> {
+    /// Creates a printer of the subtree starting at `node`, rendering values
+    /// with `Display`.
+    ///
+    /// Values are formatted without the flags of the outer formatter, such
+    /// as width or precision.
+    pub fn new(node: NodeRef<'a, T>) -> Self {
+        Self::with_render(node, |f, node| write!(f, "{}", node.value()))
+    }
+}
+
+impl<'a, T: 'a> TreePrinter<'a, 'static, T> {
+    /// Creates a printer of the subtree starting at `node`, rendering nodes
+    /// with `render`.
+    pub fn with_render<F>(node: NodeRef<'a, T>, render: F) -> Self
+    where
+        F: Fn(&mut Formatter, NodeRef<'a, T>) -> fmt::Result + 'a,
+    {
+        TreePrinter {
+            node,
+            glyphs: Glyphs::UNICODE,
+            render: Box::new(render),
+            indent_root: false,
+            max_depth: None,
+            max_children: None,
+        }
+    }
+}
+
+impl<'a, 'g, T: 'a> TreePrinter<'a, 'g, T> {
+    /// Sets the glyphs drawing the branches.
+    pub fn glyphs<'h>(self, glyphs: Glyphs<'h>) -> TreePrinter<'a, 'h, T> {
+        TreePrinter {
+            node: self.node,
+            glyphs,
+            render: self.render,
+            indent_root: self.indent_root,
+            max_depth: self.max_depth,
+            max_children: self.max_children,
+        }
+    }
+
+    /// Sets the function rendering each node.
+    pub fn render<F>(mut self, render: F) -> Self
+    where
+        F: Fn(&mut Formatter, NodeRef<'a, T>) -> fmt::Result + 'a,
+    {
+        self.render = Box::new(render);
+        self
+    }
+
+    /// Sets whether the first node is drawn with a branch, its subtree then
+    /// being indented by one level.
+    pub fn indent_root(mut self, indent_root: bool) -> Self {
+        self.indent_root = indent_root;
+        self
+    }
+
+    /// Sets the depth below the first node after which children are elided.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Sets the number of children printed for each node, after which the
+    /// remaining children are elided.
+    pub fn max_children(mut self, max_children: usize) -> Self {
+        self.max_children = Some(max_children);
+        self
+    }
+
+    fn write_node(
+        &self,
+        f: &mut Formatter,
+        indent: &mut Indentation,
+        node: NodeRef<'a, T>,
+        siblings: bool,
+    ) -> fmt::Result {
+        indent.indent(siblings);
+        write!(f, "{indent}")?;
+        (self.render)(f, node)?;
+        writeln!(f)
+    }
+
+    fn write_elision(
+        &self,
+        f: &mut Formatter,
+        indent: &mut Indentation,
+        count: usize,
+    ) -> fmt::Result {
+        indent.indent(false);
+        writeln!(f, "{indent}{} {count} more", self.glyphs.ellipsis)?;
+        indent.deindent();
+        Ok(())
+    }
+}
+
+impl<'a, T: 'a> Display for TreePrinter<'a, '_, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut indent = Indentation::new(!self.indent_root, self.glyphs);
+
+        // Children left to print at each open level, with the number of
+        // children printed so far.
+        let mut stack = Vec::new();
+
+        self.write_node(f, &mut indent, self.node, false)?;
+        let mut next = Some(self.node);
+
+        loop {
+            // Open the children of the node just printed.
+            if let Some(node) = next.take() {
+                if node.has_children() {
+                    if self.max_depth.is_some_and(|max| stack.len() >= max) {
+                        self.write_elision(f, &mut indent, node.children().count())?;
+                    } else {
+                        stack.push((node.children(), 0));
+                        continue;
+                    }
+                }
+                indent.deindent();
+            }
+
+            let Some((children, printed)) = stack.last_mut() else {
+                return Ok(());
+            };
+            match children.next() {
+                None => {
+                    stack.pop();
+                    indent.deindent();
+                }
+                Some(_) if self.max_children == Some(*printed) => {
+                    let count = 1 + children.count();
+                    self.write_elision(f, &mut indent, count)?;
+                }
+                Some(child) => {
+                    *printed += 1;
+                    self.write_node(f, &mut indent, child, child.next_sibling().is_some())?;
+                    next = Some(child);
+                }
+            }
+        }
+    }
+}
+
+/// Debug view of the structure of a tree.
+///
+/// Draws the root subtree as an outline with the ID of each node next to its
+/// value, followed by the subtrees of orphan nodes, in insert order.
+///
+/// Returned by [`Tree::debug_structure`].
+///
+/// # Examples
+///
+/// ```
+/// use ego_tree::tree;
+///
+/// let mut tree = tree!("root" => { "a", "b" => { "c" } });
+/// let b = tree.root().last_child().unwrap().id();
+/// tree.get_mut(b).unwrap().detach();
+/// tree.orphan("d");
+/// assert_eq!(
+///     format!("{:?}", tree.debug_structure()),
+///     concat!(
+///         "0: \"root\"\n",
+///         "└── 1: \"a\"\n",
+///         "orphans:\n",
+///         "2: \"b\"\n",
+///         "└── 3: \"c\"\n",
+///         "4: \"d\"\n",
+///     ),
+/// );
+/// ```
+pub struct DebugStructure<'a, T> {
+    tree: &'a Tree<T>,
+}
+impl<T> Copy for DebugStructure<'_, T> {}
+impl<T> Clone for DebugStructure<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Debug> Debug for DebugStructure<'_, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let render =
+            |f: &mut Formatter, node: NodeRef<T>| write!(f, "{}: {:?}", node.id(), node.value());
+        let root = self.tree.root();
+        Display::fmt(&TreePrinter::with_render(root, render), f)?;
+
+        let mut orphans = self
+            .tree
+            .nodes()
+            .filter(|node| node.parent().is_none() && *node != root)
+            .peekable();
+        if orphans.peek().is_some() {
+            writeln!(f, "orphans:")?;
+        }
+        for orphan in orphans {
+            Display::fmt(&TreePrinter::with_render(orphan, render), f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Tree<T> {
+    /// Returns a debug view of the tree showing node IDs and orphans.
+    ///
+    /// See [`DebugStructure`].
+    pub fn debug_structure(&self) -> DebugStructure<'_, T> {
+        DebugStructure { tree: self }
+    }
+}
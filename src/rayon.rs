@@ -0,0 +1,104 @@
+//! Parallel iteration and mapping with `rayon`.
+
+use std::ptr;
+
+use rayon::Scope;
+use rayon::prelude::*;
+
+use crate::{NodeId, NodeMut, Tree};
+
+/// Raw pointer to the nodes of a tree, shared between threads which access
+/// disjoint nodes.
+struct NodesPtr<T>(*mut crate::Node<T>);
+impl<T> Copy for NodesPtr<T> {}
+impl<T> Clone for NodesPtr<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+unsafe impl<T: Send> Send for NodesPtr<T> {}
+unsafe impl<T: Send> Sync for NodesPtr<T> {}
+
+impl<T> NodesPtr<T> {
+    // Taking `self` by value makes closures capture the whole `Send` wrapper
+    // rather than its raw pointer field.
+    fn get(self) -> *mut crate::Node<T> {
+        self.0
+    }
+}
+
+impl<T: Sync> Tree<T> {
+    /// Returns a parallel iterator over values in insert order.
+    pub fn par_values(&self) -> impl IndexedParallelIterator<Item = &T> {
+        self.vec.par_iter().map(|node| &node.value)
+    }
+}
+
+impl<T: Send> Tree<T> {
+    /// Returns a parallel mutable iterator over values in insert order.
+    pub fn par_values_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut T> {
+        self.vec.par_iter_mut().map(|node| &mut node.value)
+    }
+
+    /// Maps a `Tree<T>` to `Tree<U>` by applying a function to all node values
+    /// in parallel, copying over the tree's structure and node ids untouched,
+    /// consuming `self`.
+    pub fn par_map<F, U>(self, transform: F) -> Tree<U>
+    where
+        F: Fn(T) -> U + Sync + Send,
+        U: Send,
+    {
+        Tree {
+            vec: self
+                .vec
+                .into_par_iter()
+                .map(|node| node.map(&transform))
+                .collect(),
+        }
+    }
+}
+
+impl<'a, T: Send + 'a> NodeMut<'a, T> {
+    /// Apply function in parallel to the value of this node and each
+    /// descendant.
+    ///
+    /// Child subtrees are processed as separate tasks, each following its
+    /// first children on the same thread. Each value is visited exactly once,
+    /// in no particular order.
+    pub fn par_for_each_descendant<F>(&mut self, f: F)
+    where
+        F: Fn(&mut T) + Sync + Send,
+    {
+        let nodes = NodesPtr(self.tree.vec.as_mut_ptr());
+        let id = self.id;
+        rayon::scope(|scope| for_each_in_subtree(scope, nodes, id, &f));
+    }
+}
+
+/// Applies `f` to the subtree of `id`, spawning a task for each child other
+/// than the first, which is followed in place.
+fn for_each_in_subtree<'s, T, F>(scope: &Scope<'s>, nodes: NodesPtr<T>, mut id: NodeId, f: &'s F)
+where
+    T: Send + 's,
+    F: Fn(&mut T) + Sync,
+{
+    loop {
+        // Safety: IDs come from the tree, so they are in bounds. Each node
+        // is visited by exactly one task, since child subtrees are disjoint,
+        // and only values are borrowed mutably. Structure fields are only
+        // read, without borrowing whole nodes.
+        let node = unsafe { nodes.get().add(id.to_index()) };
+        let children = unsafe { (*node).children };
+        f(unsafe { &mut *ptr::addr_of_mut!((*node).value) });
+
+        let Some((first, _)) = children else {
+            return;
+        };
+        let mut next = unsafe { (*nodes.get().add(first.to_index())).next_sibling };
+        while let Some(sibling) = next {
+            scope.spawn(move |scope| for_each_in_subtree(scope, nodes, sibling, f));
+            next = unsafe { (*nodes.get().add(sibling.to_index())).next_sibling };
+        }
+        id = first;
+    }
+}
@@ -0,0 +1,382 @@
+//! Subtree aggregation.
+//!
+//! A [`SummaryTree`] keeps a [`Summary`] of every subtree, similar to the
+//! summaries of a rope. Summaries are recomputed up the ancestor chain on
+//! each edit made through the wrapper, and can be used to seek to the node at
+//! a cumulative position in document order.
+
+use std::cmp::Ordering;
+
+use crate::{NodeId, NodeRef, Tree};
+
+/// Aggregate of the values of a subtree.
+///
+/// The summary of a subtree is the summary of its root value combined with
+/// the summaries of its children, in order. `Default` must return the
+/// identity of `combine`.
+pub trait Summary<T>: Clone + Default {
+    /// Returns the summary of a single value.
+    fn from_value(value: &T) -> Self;
+
+    /// Combines this summary with the summary of the values following it.
+    fn combine(&mut self, other: &Self);
+}
+
+/// Tree keeping a summary of every subtree.
+///
+/// # Examples
+///
+/// ```
+/// use ego_tree::summary::{Summary, SummaryTree};
+/// use ego_tree::tree;
+///
+/// #[derive(Debug, Clone, Default, PartialEq)]
+/// struct Len(usize);
+///
+/// impl Summary<&str> for Len {
+///     fn from_value(value: &&str) -> Self {
+///         Len(value.len())
+///     }
+///
+///     fn combine(&mut self, other: &Self) {
+///         self.0 += other.0;
+///     }
+/// }
+///
+/// let mut tree = SummaryTree::<_, Len>::from(tree!("ab" => { "cde", "f" }));
+/// let root = tree.tree().root().id();
+/// assert_eq!(Some(&Len(6)), tree.summary(root));
+///
+/// tree.append(root, "gh");
+/// assert_eq!(Some(&Len(8)), tree.summary(root));
+///
+/// // Find the node containing offset 5.
+/// let (node, offset) = tree.seek(|len| len.0 > 5).unwrap();
+/// assert_eq!(&"f", node.value());
+/// assert_eq!(Len(5), offset);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SummaryTree<T, S> {
+    tree: Tree<T>,
+    summaries: Vec<S>,
+}
+
+impl<T, S: Summary<T>> From<Tree<T>> for SummaryTree<T, S> {
+    fn from(tree: Tree<T>) -> Self {
+        let mut summary_tree = SummaryTree {
+            summaries: tree.values().map(S::from_value).collect(),
+            tree,
+        };
+        let roots = summary_tree
+            .tree
+            .nodes()
+            .filter(|node| node.parent().is_none())
+            .map(|node| node.id())
+            .collect::<Vec<_>>();
+        for root in roots {
+            let ids = summary_tree
+                .tree
+                .get(root)
+                .unwrap()
+                .traverse()
+                .filter_map(|edge| match edge {
+                    crate::iter::Edge::Close(node) => Some(node.id()),
+                    crate::iter::Edge::Open(_) => None,
+                })
+                .collect::<Vec<_>>();
+            for id in ids {
+                summary_tree.recompute(id);
+            }
+        }
+        summary_tree
+    }
+}
+
+impl<T, S: Summary<T>> SummaryTree<T, S> {
+    /// Creates a tree with a root node.
+    pub fn new(root: T) -> Self {
+        Tree::new(root).into()
+    }
+
+    /// Returns the underlying tree.
+    pub fn tree(&self) -> &Tree<T> {
+        &self.tree
+    }
+
+    /// Returns the underlying tree, discarding the summaries.
+    pub fn into_tree(self) -> Tree<T> {
+        self.tree
+    }
+
+    /// Returns the summary of the subtree starting at the specified node.
+    pub fn summary(&self, id: NodeId) -> Option<&S> {
+        self.summaries.get(id.to_index())
+    }
+
+    fn recompute(&mut self, id: NodeId) {
+        let node = self.tree.get(id).unwrap();
+        let mut summary = S::from_value(node.value());
+        for child in node.children() {
+            summary.combine(&self.summaries[child.id().to_index()]);
+        }
+        self.summaries[id.to_index()] = summary;
+    }
+
+    fn refresh(&mut self, id: Option<NodeId>) {
+        let mut id = id;
+        while let Some(current) = id {
+            self.recompute(current);
+            id = self
+                .tree
+                .get(current)
+                .unwrap()
+                .parent()
+                .map(|node| node.id());
+        }
+    }
+
+    fn parent_id(&self, id: NodeId) -> Option<NodeId> {
+        self.tree.get(id).unwrap().parent().map(|node| node.id())
+    }
+
+    /// Applies a function to the value of the specified node, then updates
+    /// the summaries of the node and its ancestors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not valid.
+    pub fn update<F, R>(&mut self, id: NodeId, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let result = f(self.tree.get_mut(id).unwrap().value());
+        self.refresh(Some(id));
+        result
+    }
+
+    /// Creates an orphan node.
+    pub fn orphan(&mut self, value: T) -> NodeId {
+        self.summaries.push(S::from_value(&value));
+        self.tree.orphan(value).id()
+    }
+
+    /// Appends a new child to the specified node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` is not valid.
+    pub fn append(&mut self, parent: NodeId, value: T) -> NodeId {
+        let id = self.orphan(value);
+        self.append_id(parent, id);
+        id
+    }
+
+    /// Prepends a new child to the specified node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` is not valid.
+    pub fn prepend(&mut self, parent: NodeId, value: T) -> NodeId {
+        let id = self.orphan(value);
+        self.prepend_id(parent, id);
+        id
+    }
+
+    /// Inserts a new sibling before the specified node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sibling` is not valid or is an orphan.
+    pub fn insert_before(&mut self, sibling: NodeId, value: T) -> NodeId {
+        let id = self.orphan(value);
+        self.insert_id_before(sibling, id);
+        id
+    }
+
+    /// Inserts a new sibling after the specified node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sibling` is not valid or is an orphan.
+    pub fn insert_after(&mut self, sibling: NodeId, value: T) -> NodeId {
+        let id = self.orphan(value);
+        self.insert_id_after(sibling, id);
+        id
+    }
+
+    /// Appends a child to the specified node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` or `child` is not valid.
+    pub fn append_id(&mut self, parent: NodeId, child: NodeId) {
+        let old_parent = self.parent_id(child);
+        self.tree.get_mut(parent).unwrap().append_id(child);
+        self.refresh(old_parent);
+        self.refresh(Some(parent));
+    }
+
+    /// Prepends a child to the specified node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` or `child` is not valid.
+    pub fn prepend_id(&mut self, parent: NodeId, child: NodeId) {
+        let old_parent = self.parent_id(child);
+        self.tree.get_mut(parent).unwrap().prepend_id(child);
+        self.refresh(old_parent);
+        self.refresh(Some(parent));
+    }
+
+    /// Inserts a sibling before the specified node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sibling` or `new_sibling` is not valid, or if `sibling` is
+    /// an orphan.
+    pub fn insert_id_before(&mut self, sibling: NodeId, new_sibling: NodeId) {
+        let old_parent = self.parent_id(new_sibling);
+        self.tree
+            .get_mut(sibling)
+            .unwrap()
+            .insert_id_before(new_sibling);
+        self.refresh(old_parent);
+        self.refresh(self.parent_id(sibling));
+    }
+
+    /// Inserts a sibling after the specified node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sibling` or `new_sibling` is not valid, or if `sibling` is
+    /// an orphan.
+    pub fn insert_id_after(&mut self, sibling: NodeId, new_sibling: NodeId) {
+        let old_parent = self.parent_id(new_sibling);
+        self.tree
+            .get_mut(sibling)
+            .unwrap()
+            .insert_id_after(new_sibling);
+        self.refresh(old_parent);
+        self.refresh(self.parent_id(sibling));
+    }
+
+    /// Detaches the specified node from its parent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not valid.
+    pub fn detach(&mut self, id: NodeId) {
+        let old_parent = self.parent_id(id);
+        self.tree.get_mut(id).unwrap().detach();
+        self.refresh(old_parent);
+    }
+
+    /// Reparents the children of a node, appending them to the specified
+    /// node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` or `from_id` is not valid, or if they are equal.
+    pub fn reparent_from_id_append(&mut self, parent: NodeId, from_id: NodeId) {
+        self.tree
+            .get_mut(parent)
+            .unwrap()
+            .reparent_from_id_append(from_id);
+        self.refresh(Some(from_id));
+        self.refresh(Some(parent));
+    }
+
+    /// Reparents the children of a node, prepending them to the specified
+    /// node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` or `from_id` is not valid, or if they are equal.
+    pub fn reparent_from_id_prepend(&mut self, parent: NodeId, from_id: NodeId) {
+        self.tree
+            .get_mut(parent)
+            .unwrap()
+            .reparent_from_id_prepend(from_id);
+        self.refresh(Some(from_id));
+        self.refresh(Some(parent));
+    }
+
+    /// Sorts the children of the specified node by value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not valid.
+    pub fn sort(&mut self, id: NodeId)
+    where
+        T: Ord,
+    {
+        self.tree.get_mut(id).unwrap().sort();
+        self.refresh(Some(id));
+    }
+
+    /// Sorts the children of the specified node with a comparator function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not valid.
+    pub fn sort_by<F>(&mut self, id: NodeId, compare: F)
+    where
+        F: FnMut(NodeRef<T>, NodeRef<T>) -> Ordering,
+    {
+        self.tree.get_mut(id).unwrap().sort_by(compare);
+        self.refresh(Some(id));
+    }
+
+    /// Sorts the children of the specified node with a key extraction
+    /// function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not valid.
+    pub fn sort_by_key<K, F>(&mut self, id: NodeId, f: F)
+    where
+        F: FnMut(NodeRef<T>) -> K,
+        K: Ord,
+    {
+        self.tree.get_mut(id).unwrap().sort_by_key(f);
+        self.refresh(Some(id));
+    }
+
+    /// Seeks the first node of the root subtree, in document order, at which
+    /// `pred` returns true for the summary of all values up to and including
+    /// that node.
+    ///
+    /// Returns the node along with the summary of the values preceding it.
+    /// `pred` must be monotonic: once true for a prefix, it must be true for
+    /// every longer prefix.
+    ///
+    /// Takes time proportional to the depth of the found node times the
+    /// number of children of its ancestors.
+    pub fn seek<F>(&self, mut pred: F) -> Option<(NodeRef<'_, T>, S)>
+    where
+        F: FnMut(&S) -> bool,
+    {
+        let mut node = self.tree.root();
+        let mut prefix = S::default();
+
+        'descend: loop {
+            let mut with_node = prefix.clone();
+            with_node.combine(&S::from_value(node.value()));
+            if pred(&with_node) {
+                return Some((node, prefix));
+            }
+            prefix = with_node;
+
+            for child in node.children() {
+                let mut with_child = prefix.clone();
+                with_child.combine(&self.summaries[child.id().to_index()]);
+                if pred(&with_child) {
+                    node = child;
+                    continue 'descend;
+                }
+                prefix = with_child;
+            }
+            return None;
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Rust source for the `tree!` macro.
+//!
+//! This module provides a method writing a tree as a `tree!` invocation,
+//! so that trees built at runtime can be pasted into tests.
+
+use std::fmt::{Debug, Write};
+
+use crate::Tree;
+use crate::iter::Edge;
+
+const INDENT: &str = "    ";
+
+impl<T: Debug> Tree<T> {
+    /// Returns the root subtree as an invocation of the [`tree!`](crate::tree)
+    /// macro, writing values with `Debug`.
+    ///
+    /// The output is valid macro input whenever the `Debug` output of each
+    /// value is a valid Rust expression. Note that values such as `String`
+    /// are written as literals of a different type, e.g. `&str`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ego_tree::tree;
+    ///
+    /// let tree = tree!(1 => { 2 => { 3 }, 4 });
+    /// assert_eq!(
+    ///     tree.to_tree_macro(),
+    ///     "tree! {
+    ///     1 => {
+    ///         2 => {
+    ///             3,
+    ///         },
+    ///         4,
+    ///     }
+    /// }",
+    /// );
+    /// ```
+    pub fn to_tree_macro(&self) -> String {
+        let root = self.root();
+        let mut out = String::from("tree! {\n");
+        let mut depth = 1;
+
+        for edge in root.traverse() {
+            match edge {
+                Edge::Open(node) => {
+                    out.push_str(&INDENT.repeat(depth));
+                    write!(out, "{:?}", node.value()).unwrap();
+                    if node.has_children() {
+                        out.push_str(" => {\n");
+                        depth += 1;
+                        continue;
+                    }
+                }
+                Edge::Close(node) => {
+                    if !node.has_children() {
+                        continue;
+                    }
+                    depth -= 1;
+                    out.push_str(&INDENT.repeat(depth));
+                    out.push('}');
+                }
+            }
+            if depth > 1 {
+                out.push(',');
+            }
+            out.push('\n');
+        }
+
+        out.push('}');
+        out
+    }
+}
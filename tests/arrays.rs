@@ -0,0 +1,56 @@
+use ego_tree::{Tree, tree};
+
+#[test]
+fn parent_array() {
+    let tree = tree!(0 => { 1 => { 2, 3 }, 4 => { 5 }, 6 });
+    let (values, parents) = tree.to_parent_array();
+    assert_eq!(vec![0, 1, 2, 3, 4, 5, 6], values);
+    assert_eq!(
+        vec![None, Some(0), Some(1), Some(1), Some(0), Some(4), Some(0)],
+        parents
+    );
+}
+
+#[test]
+fn child_sibling_arrays() {
+    let tree = tree!(0 => { 1 => { 2, 3 }, 4 => { 5 }, 6 });
+    let (values, first_children, next_siblings) = tree.to_child_sibling_arrays();
+    assert_eq!(vec![0, 1, 2, 3, 4, 5, 6], values);
+    assert_eq!(
+        vec![Some(1), Some(2), None, None, Some(5), None, None],
+        first_children
+    );
+    assert_eq!(
+        vec![None, Some(4), Some(3), None, Some(6), None, None],
+        next_siblings
+    );
+}
+
+#[test]
+fn document_order_not_insert_order() {
+    let mut tree = Tree::new('a');
+    let c = tree.root_mut().append('c').id();
+    tree.root_mut().prepend('b');
+    tree.get_mut(c).unwrap().prepend('d');
+    tree.orphan('x');
+
+    let (values, parents) = tree.to_parent_array();
+    assert_eq!(vec!['a', 'b', 'c', 'd'], values);
+    assert_eq!(vec![None, Some(0), Some(0), Some(2)], parents);
+
+    let (_, first_children, next_siblings) = tree.to_child_sibling_arrays();
+    assert_eq!(vec![Some(1), None, Some(3), None], first_children);
+    assert_eq!(vec![None, Some(2), None, None], next_siblings);
+}
+
+#[test]
+fn parent_links_round_trip() {
+    let tree = tree!("root" => { "a" => { "b", "c" }, "d" });
+    let (values, parents) = tree.to_parent_array();
+    let links = values
+        .into_iter()
+        .zip(parents)
+        .enumerate()
+        .map(|(i, (value, parent))| (i, parent, value));
+    assert_eq!(tree, Tree::from_parent_links(links).unwrap());
+}
@@ -0,0 +1,155 @@
+use ego_tree::builder::{BuildError, Event, LinkError, TreeBuilder};
+use ego_tree::iter::Edge;
+use ego_tree::{Tree, tree};
+
+#[test]
+fn build() {
+    let mut builder = TreeBuilder::new();
+    let root = builder.open('a').unwrap();
+    builder.leaf('b').unwrap();
+    let c = builder.open('c').unwrap();
+    assert_eq!(2, builder.depth());
+    let d = builder.leaf('d').unwrap();
+    assert_eq!(Ok(c), builder.close());
+    builder.open('e').unwrap();
+    builder.close().unwrap();
+    assert_eq!(Ok(root), builder.close());
+    assert_eq!(0, builder.depth());
+
+    let tree = builder.build().unwrap();
+    assert_eq!(tree!('a' => { 'b', 'c' => { 'd' }, 'e' }), tree);
+    assert_eq!(root, tree.root().id());
+    assert_eq!(&'d', tree.get(d).unwrap().value());
+}
+
+#[test]
+fn leaf_root() {
+    let mut builder = TreeBuilder::new();
+    builder.leaf(1).unwrap();
+    assert_eq!(Ok(Tree::new(1)), builder.build());
+}
+
+#[test]
+fn from_traverse() {
+    let tree = tree!(1 => { 2 => { 3, 4 }, 5 });
+    let mut builder = TreeBuilder::new();
+    for edge in tree.root().traverse() {
+        match edge {
+            Edge::Open(node) => builder.open(*node.value() * 10).map(drop),
+            Edge::Close(_) => builder.close().map(drop),
+        }
+        .unwrap();
+    }
+    assert_eq!(tree.map(|v| v * 10), builder.build().unwrap());
+}
+
+#[test]
+fn errors() {
+    let builder = TreeBuilder::<i32>::new();
+    assert_eq!(Err(BuildError::Empty), builder.build());
+
+    let mut builder = TreeBuilder::new();
+    assert_eq!(Err(BuildError::UnbalancedClose), builder.close());
+    builder.open(1).unwrap();
+    builder.open(2).unwrap();
+    assert_eq!(Err(BuildError::Unclosed(2)), builder.build());
+
+    let mut builder = TreeBuilder::new();
+    builder.leaf(1).unwrap();
+    assert_eq!(Err(BuildError::MultipleRoots), builder.leaf(2));
+    assert_eq!(Err(BuildError::MultipleRoots), builder.open(2));
+    assert_eq!(Err(BuildError::UnbalancedClose), builder.close());
+    assert_eq!("2 unclosed nodes", BuildError::Unclosed(2).to_string());
+}
+
+#[test]
+fn from_edges() {
+    let events = vec![
+        Event::Open('a'),
+        Event::Open('b'),
+        Event::Open('c'),
+        Event::Close,
+        Event::Close,
+        Event::Open('d'),
+        Event::Close,
+        Event::Close,
+    ];
+    assert_eq!(
+        tree!('a' => { 'b' => { 'c' }, 'd' }),
+        Tree::from_edges(events).unwrap()
+    );
+
+    let tree = tree!('a' => { 'b' => { 'c' }, 'd' });
+    let node = tree.root().first_child().unwrap();
+    let subtree = Tree::from_edges(node.traverse().map(Event::from)).unwrap();
+    assert_eq!(tree!('b' => { 'c' }), subtree);
+}
+
+#[test]
+fn from_edges_errors() {
+    assert_eq!(Err(BuildError::Empty), Tree::<i32>::from_edges(Vec::new()));
+    assert_eq!(
+        Err(BuildError::UnbalancedClose),
+        Tree::from_edges([Event::Open(1), Event::Close, Event::Close])
+    );
+    assert_eq!(
+        Err(BuildError::MultipleRoots),
+        Tree::from_edges([Event::Open(1), Event::Close, Event::Open(2)])
+    );
+    assert_eq!(
+        Err(BuildError::Unclosed(1)),
+        Tree::from_edges([Event::Open(1)])
+    );
+}
+
+#[test]
+fn from_parent_links() {
+    let links = vec![
+        ("c", Some("a"), 3),
+        ("a", Some("root"), 1),
+        ("root", None, 0),
+        ("b", Some("root"), 2),
+        ("d", Some("a"), 4),
+    ];
+    let tree = Tree::from_parent_links(links).unwrap();
+    assert_eq!(tree!(0 => { 1 => { 3, 4 }, 2 }), tree);
+
+    let tree = Tree::from_parent_links([(7, None, 'x')]).unwrap();
+    assert_eq!(Tree::new('x'), tree);
+}
+
+#[test]
+fn from_parent_links_errors() {
+    assert_eq!(
+        Err(LinkError::Empty),
+        Tree::<()>::from_parent_links(Vec::<(i32, _, _)>::new())
+    );
+    assert_eq!(
+        Err(LinkError::MultipleRoots(2)),
+        Tree::from_parent_links([(1, None, ()), (2, None, ())])
+    );
+    assert_eq!(
+        Err(LinkError::DuplicateKey(1)),
+        Tree::from_parent_links([(1, None, ()), (1, Some(1), ())])
+    );
+    assert_eq!(
+        Err(LinkError::UnknownParent(3)),
+        Tree::from_parent_links([(1, None, ()), (2, Some(3), ())])
+    );
+
+    // 4 hangs below the cycle 2 -> 3 -> 2.
+    let links = [
+        (1, None, ()),
+        (4, Some(3), ()),
+        (2, Some(3), ()),
+        (3, Some(2), ()),
+    ];
+    assert_eq!(Err(LinkError::Cycle(3)), Tree::from_parent_links(links));
+    let links = [(1, Some(2), ()), (2, Some(1), ())];
+    assert_eq!(Err(LinkError::Cycle(1)), Tree::from_parent_links(links));
+    assert_eq!(
+        Err(LinkError::Cycle(1)),
+        Tree::from_parent_links([(0, None, ()), (1, Some(1), ())])
+    );
+    assert_eq!("unknown parent: 3", LinkError::UnknownParent(3).to_string());
+}
@@ -0,0 +1,64 @@
+use ego_tree::dot::Dot;
+use ego_tree::tree;
+
+#[test]
+fn to_dot() {
+    let mut tree = tree!("root" => { "a" => { "c" }, "b" });
+    tree.orphan("orphan");
+    assert_eq!(
+        "digraph {\n    \
+            0 [label=\"root\"];\n    \
+            1 [label=\"a\"];\n    \
+            2 [label=\"c\"];\n    \
+            3 [label=\"b\"];\n    \
+            0 -> 1;\n    \
+            1 -> 2;\n    \
+            0 -> 3;\n\
+        }\n",
+        tree.to_dot()
+    );
+}
+
+#[test]
+fn label_escaping() {
+    let tree = tree!("say \"hi\"\\" => { "two\nlines" });
+    let dot = tree.to_dot();
+    assert!(dot.contains(r#"0 [label="say \"hi\"\\"];"#));
+    assert!(dot.contains(r#"1 [label="two\nlines"];"#));
+}
+
+#[test]
+fn with_label_and_attributes() {
+    let tree = tree!(1 => { 2, 3 });
+    let dot = Dot::with_label(&tree, |node| format!("#{}", node.value()))
+        .attributes(|node| {
+            if node.has_children() {
+                "shape=box".to_string()
+            } else {
+                String::new()
+            }
+        })
+        .to_string();
+    assert!(dot.contains("0 [label=\"#1\", shape=box];"));
+    assert!(dot.contains("1 [label=\"#2\"];"));
+}
+
+#[test]
+fn sibling_edges() {
+    let tree = tree!('a' => { 'b', 'c', 'd' });
+    let dot = Dot::new(&tree).sibling_edges(true).to_string();
+    assert!(dot.contains("1 -> 2 [style=dashed, constraint=false];"));
+    assert!(dot.contains("2 -> 3 [style=dashed, constraint=false];"));
+    assert!(!dot.contains("3 -> "));
+}
+
+#[test]
+fn orphans() {
+    let mut tree = tree!('a' => { 'b' });
+    tree.orphan('c').append('d');
+    let dot = Dot::new(&tree).orphans(true).to_string();
+    assert!(dot.contains("2 [label=\"c\"];"));
+    assert!(dot.contains("3 [label=\"d\"];"));
+    assert!(dot.contains("2 -> 3;"));
+    assert!(!Dot::new(&tree).to_string().contains("label=\"c\""));
+}
@@ -0,0 +1,81 @@
+use ego_tree::{FilterMode, tree};
+
+#[test]
+fn filter_prune() {
+    let tree = tree!('a' => { 'b' => { 'c', 'd' }, 'e' => { 'f', 'g' }, 'h' });
+    let mut calls = Vec::new();
+    let (filtered, ids) = tree
+        .filter(FilterMode::Prune, |n| {
+            calls.push(*n.value());
+            !matches!(n.value(), 'b' | 'g')
+        })
+        .unwrap();
+
+    assert_eq!(tree!('a' => { 'e' => { 'f' }, 'h' }), filtered);
+    assert_eq!(vec!['a', 'b', 'e', 'f', 'g', 'h'], calls);
+
+    assert_eq!(4, ids.len());
+    let b = tree.root().first_child().unwrap().id();
+    assert!(!ids.contains_key(b));
+    assert_eq!(filtered.root().id(), ids[tree.root().id()]);
+    for (old, new) in ids {
+        assert_eq!(
+            tree.get(old).unwrap().value(),
+            filtered.get(new).unwrap().value()
+        );
+    }
+}
+
+#[test]
+fn filter_hoist() {
+    let tree = tree!('a' => { 'b' => { 'c', 'd' => { 'x' } }, 'e' => { 'f', 'g' }, 'h' });
+    let (filtered, ids) = tree
+        .filter(FilterMode::Hoist, |n| !matches!(n.value(), 'b' | 'd' | 'g'))
+        .unwrap();
+
+    assert_eq!(tree!('a' => { 'c', 'x', 'e' => { 'f' }, 'h' }), filtered);
+    assert_eq!(6, ids.len());
+    for (old, new) in ids {
+        assert_eq!(
+            tree.get(old).unwrap().value(),
+            filtered.get(new).unwrap().value()
+        );
+    }
+}
+
+#[test]
+fn filter_root() {
+    let tree = tree!('a' => { 'b' });
+    assert!(
+        tree.filter(FilterMode::Prune, |n| *n.value() != 'a')
+            .is_none()
+    );
+    assert!(
+        tree.filter(FilterMode::Hoist, |n| *n.value() != 'a')
+            .is_none()
+    );
+}
+
+#[test]
+fn filter_ignores_orphans() {
+    let mut tree = tree!('a' => { 'b' });
+    tree.orphan('c');
+    let (filtered, _) = tree.filter(FilterMode::Prune, |_| true).unwrap();
+    assert_eq!(tree!('a' => { 'b' }), filtered);
+}
+
+#[test]
+fn retain_descendants() {
+    let mut tree = tree!('a' => { 'b' => { 'c', 'd' }, 'e' => { 'f', 'g' }, 'h' });
+    let e = tree.root().nth_child(1).unwrap().id();
+    tree.get_mut(e)
+        .unwrap()
+        .retain_descendants(|n| *n.value() != 'f');
+    tree.root_mut().retain_descendants(|n| *n.value() != 'b');
+
+    assert_eq!(
+        tree!('a' => { 'e' => { 'g' }, 'h' }).to_string(),
+        tree.to_string()
+    );
+    assert_eq!(8, tree.nodes().count());
+}
@@ -0,0 +1,71 @@
+use ego_tree::tree;
+
+#[test]
+fn find() {
+    let tree = tree!(1 => { 2 => { 3, 4 }, 5 });
+    assert_eq!(&1, tree.find(|n| *n.value() > 0).unwrap().value());
+    assert_eq!(&3, tree.find(|n| *n.value() > 2).unwrap().value());
+    assert_eq!(None, tree.find(|n| *n.value() > 5));
+}
+
+#[test]
+fn find_stops_early() {
+    let tree = tree!(1 => { 2 => { 3, 4 }, 5 });
+    let mut calls = 0;
+    tree.find(|n| {
+        calls += 1;
+        *n.value() == 3
+    });
+    assert_eq!(3, calls);
+}
+
+#[test]
+fn find_all() {
+    let mut tree = tree!(1 => { 2 => { 3, 4 }, 5 });
+    tree.orphan(6);
+    assert_eq!(
+        vec![&1, &3, &5],
+        tree.find_all(|n| n.value() % 2 == 1)
+            .map(|n| n.value())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn find_descendant() {
+    let tree = tree!(1 => { 2 => { 1, 4 }, 1 });
+    let found = tree.root().find_descendant(|n| *n.value() == 1).unwrap();
+    assert_eq!(Some(2), found.parent().map(|p| *p.value()));
+    assert_eq!(
+        None,
+        tree.root()
+            .first_child()
+            .unwrap()
+            .find_descendant(|n| *n.value() == 2)
+    );
+}
+
+#[test]
+fn find_ancestor() {
+    let tree = tree!(1 => { 2 => { 3 => { 4 } } });
+    let leaf = tree.root().last_children().last().unwrap();
+    assert_eq!(
+        &2,
+        leaf.find_ancestor(|n| n.value() % 2 == 0).unwrap().value()
+    );
+    assert_eq!(None, leaf.find_ancestor(|n| *n.value() == 4));
+}
+
+#[test]
+fn position_among_siblings() {
+    let mut tree = tree!('a' => { 'b', 'c', 'd' });
+    let orphan = tree.orphan('e').id();
+    let positions = tree
+        .root()
+        .children()
+        .map(|n| n.position_among_siblings())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![0, 1, 2], positions);
+    assert_eq!(0, tree.root().position_among_siblings());
+    assert_eq!(0, tree.get(orphan).unwrap().position_among_siblings());
+}
@@ -0,0 +1,74 @@
+use ego_tree::node_map::NodeMap;
+use ego_tree::{NodeRef, Tree, tree};
+
+/// Returns the results of `map` for the nodes of `node`'s subtree in document
+/// order.
+fn in_order<T, U: Copy>(node: NodeRef<T>, map: &NodeMap<U>) -> Vec<U> {
+    node.descendants().map(|node| map[node.id()]).collect()
+}
+
+#[test]
+fn fold_up() {
+    let tree = tree!(1 => { 2 => { 3, 4 => { 5 } }, 6 });
+    let sums = tree.root().fold_up(
+        |value| *value,
+        |value, children| value + children.iter().sum::<i32>(),
+    );
+    assert_eq!(6, sums.len());
+    assert_eq!(vec![21, 14, 3, 9, 5, 6], in_order(tree.root(), &sums));
+}
+
+#[test]
+fn fold_up_subtree() {
+    let tree = tree!('a' => { 'b' => { 'c', 'd' => { 'e' } }, 'f' });
+    let b = tree.root().first_child().unwrap();
+    let heights = b.fold_up(|_| 0, |_, children| 1 + children.iter().max().unwrap());
+    assert_eq!(4, heights.len());
+    assert_eq!(vec![2, 0, 1, 0], in_order(b, &heights));
+    assert_eq!(None, heights.get(tree.root().id()));
+}
+
+#[test]
+fn fold_up_leaf() {
+    let tree = tree!('a');
+    let results = tree.root().fold_up(|_| 1, |_, _| 2);
+    assert_eq!(Some(&1), results.get(tree.root().id()));
+}
+
+#[test]
+fn fold_down() {
+    let tree = tree!(1 => { 2 => { 3, 4 => { 5 } }, 6 });
+    let depths = tree.root().fold_down(-1, |parent, _| parent + 1);
+    assert_eq!(vec![0, 1, 2, 2, 3, 1], in_order(tree.root(), &depths));
+}
+
+#[test]
+fn fold_down_subtree() {
+    let tree = tree!(1 => { 2 => { 3, 4 => { 5 } }, 6 });
+    let b = tree.root().first_child().unwrap();
+    let sums = b.fold_down(0, |parent, value| parent + value);
+    assert_eq!(4, sums.len());
+    assert_eq!(vec![2, 5, 6, 11], in_order(b, &sums));
+}
+
+#[test]
+fn ids_not_in_document_order() {
+    let mut tree = Tree::new(1);
+    let c = tree.root_mut().append(3).id();
+    let b = tree.root_mut().prepend(2).id();
+    let d = tree.get_mut(c).unwrap().prepend(4).id();
+
+    let sums = tree
+        .root()
+        .fold_up(|v| *v, |v, children| v + children.iter().sum::<i32>());
+    assert_eq!(10, sums[tree.root().id()]);
+    assert_eq!(2, sums[b]);
+    assert_eq!(7, sums[c]);
+    assert_eq!(4, sums[d]);
+
+    let depths = tree.root().fold_down(0, |parent, _| parent + 1);
+    assert_eq!(1, depths[tree.root().id()]);
+    assert_eq!(2, depths[b]);
+    assert_eq!(2, depths[c]);
+    assert_eq!(3, depths[d]);
+}
@@ -0,0 +1,175 @@
+use std::convert::Infallible;
+
+use ego_tree::format::{FormatError, FormatErrorKind, newick, sexpr};
+use ego_tree::{Tree, tree};
+
+fn newick_label(name: &str, length: Option<f64>) -> Result<(String, Option<f64>), Infallible> {
+    Ok((name.to_string(), length))
+}
+
+fn sexpr_label(atom: &str) -> Result<String, Infallible> {
+    Ok(atom.to_string())
+}
+
+#[test]
+fn newick_parse() {
+    let tree = newick::parse("((A:0.1, B:0.2)C:1, 'D d''s')E;\n", newick_label).unwrap();
+    let expected = tree!(("E", None) => {
+        ("C", Some(1.0)) => { ("A", Some(0.1)), ("B", Some(0.2)) },
+        ("D d's", None),
+    });
+    assert_eq!(
+        expected.map(|(name, length)| (name.to_string(), length)),
+        tree
+    );
+
+    let tree = newick::parse("(,);", newick_label).unwrap();
+    assert_eq!(3, tree.nodes().count());
+    assert!(
+        tree.values()
+            .all(|(name, length)| name.is_empty() && length.is_none())
+    );
+}
+
+#[test]
+fn newick_round_trip() {
+    let input = "((A:0.1,B:0.2)C:1,'D d''s',(F)G)E;";
+    let tree = newick::parse(input, newick_label).unwrap();
+    let output = newick::to_string(tree.root(), |(name, length)| (name.clone(), *length));
+    assert_eq!(input, output);
+}
+
+#[test]
+fn newick_subtree() {
+    let tree = newick::parse("((A,B)C,D)E;", newick_label).unwrap();
+    let c = tree.root().first_child().unwrap();
+    assert_eq!(
+        "(A,B)C;",
+        newick::to_string(c, |(name, length)| (name.clone(), *length))
+    );
+}
+
+#[test]
+fn newick_errors() {
+    let error = |input| newick::parse(input, newick_label).unwrap_err();
+
+    assert_eq!(
+        FormatError {
+            line: 1,
+            column: 7,
+            kind: FormatErrorKind::UnexpectedEnd,
+        },
+        error("(A,B)C")
+    );
+    assert_eq!(
+        FormatError {
+            line: 1,
+            column: 5,
+            kind: FormatErrorKind::UnexpectedChar(';'),
+        },
+        error("(A,B;")
+    );
+    assert_eq!(
+        FormatError {
+            line: 2,
+            column: 3,
+            kind: FormatErrorKind::InvalidLength,
+        },
+        error("(A,\nB:x)C;")
+    );
+    assert_eq!(
+        FormatError {
+            line: 1,
+            column: 4,
+            kind: FormatErrorKind::TrailingInput,
+        },
+        error("A; B;")
+    );
+    assert_eq!(FormatErrorKind::UnexpectedChar(')'), error("A);").kind);
+    assert_eq!(FormatErrorKind::UnexpectedEnd, error("('A").kind);
+}
+
+#[test]
+fn newick_label_error() {
+    let error = newick::parse(
+        "(A,B)C;",
+        |name, _| {
+            if name == "B" { Err("no B") } else { Ok(()) }
+        },
+    )
+    .unwrap_err();
+    assert_eq!(FormatErrorKind::Label("no B"), error.kind);
+    assert_eq!((1, 4), (error.line, error.column));
+    assert_eq!("1:4: invalid label: no B", error.to_string());
+}
+
+#[test]
+fn sexpr_parse() {
+    let tree = sexpr::parse("(define x\n  (+ 1 \"two \\\"words\\\"\") (y))", sexpr_label).unwrap();
+    let expected = tree!("define" => { "x", "+" => { "1", "two \"words\"" }, "y" });
+    assert_eq!(expected.map(String::from), tree);
+
+    let tree = sexpr::parse("  atom  ", sexpr_label).unwrap();
+    assert_eq!(Tree::new("atom".to_string()), tree);
+}
+
+#[test]
+fn sexpr_round_trip() {
+    let input = r#"(define x (+ 1 "two words" "" "\"" \) y)"#;
+    let tree = sexpr::parse(input, sexpr_label).unwrap();
+    assert_eq!(input, sexpr::to_string(tree.root(), String::clone));
+}
+
+#[test]
+fn sexpr_parse_values() {
+    let tree = sexpr::parse("(1 2 (3 4))", str::parse::<i32>).unwrap();
+    assert_eq!(tree!(1 => { 2, 3 => { 4 } }), tree);
+
+    let error = sexpr::parse("(1 x)", str::parse::<i32>).unwrap_err();
+    assert_eq!((1, 4), (error.line, error.column));
+    assert!(matches!(error.kind, FormatErrorKind::Label(_)));
+}
+
+#[test]
+fn sexpr_errors() {
+    let error = |input| sexpr::parse(input, sexpr_label).unwrap_err();
+
+    assert_eq!(FormatErrorKind::UnexpectedEnd, error("").kind);
+    assert_eq!(FormatErrorKind::UnexpectedEnd, error("(a (b c)").kind);
+    assert_eq!(FormatErrorKind::UnexpectedChar(')'), error("()").kind);
+    assert_eq!(FormatErrorKind::UnexpectedChar('('), error("((a) b)").kind);
+    assert_eq!(FormatErrorKind::UnexpectedChar('n'), error(r#""\n""#).kind);
+    assert_eq!(
+        FormatError {
+            line: 1,
+            column: 6,
+            kind: FormatErrorKind::UnexpectedChar('é'),
+        },
+        error("(a \"\\é\")")
+    );
+    assert_eq!(
+        FormatError {
+            line: 1,
+            column: 7,
+            kind: FormatErrorKind::TrailingInput,
+        },
+        error("(a b) c")
+    );
+}
+
+#[test]
+fn deep() {
+    let depth = 100_000;
+    let mut tree = Tree::new(0);
+    let mut id = tree.root().id();
+    for i in 1..depth {
+        id = tree.get_mut(id).unwrap().append(i).id();
+    }
+
+    let output = sexpr::to_string(tree.root(), i32::to_string);
+    assert_eq!(tree, sexpr::parse(&output, str::parse::<i32>).unwrap());
+
+    let output = newick::to_string(tree.root(), |i| (i.to_string(), None));
+    let parsed = newick::parse(&output, |name, _| name.parse::<i32>()).unwrap();
+    assert_eq!(tree, parsed);
+}
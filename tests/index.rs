@@ -0,0 +1,55 @@
+use ego_tree::{index::TreeIndex, tree};
+
+#[test]
+fn is_ancestor_of() {
+    let mut tree = tree!('a' => { 'b' => { 'c', 'd' }, 'e' });
+    tree.orphan('f').append('g');
+    let index = TreeIndex::new(&tree);
+
+    for a in tree.nodes() {
+        for b in tree.nodes() {
+            assert_eq!(a.is_ancestor_of(&b), index.is_ancestor_of(a.id(), b.id()));
+        }
+    }
+}
+
+#[test]
+fn subtree_size() {
+    let mut tree = tree!('a' => { 'b' => { 'c', 'd' }, 'e' });
+    tree.orphan('f').append('g');
+    let index = TreeIndex::new(&tree);
+
+    for node in tree.nodes() {
+        assert_eq!(node.descendants().count(), index.subtree_size(node.id()));
+    }
+}
+
+#[test]
+fn depth() {
+    let tree = tree!('a' => { 'b' => { 'c' => { 'd' } }, 'e' });
+    let index = TreeIndex::new(&tree);
+
+    for node in tree.nodes() {
+        assert_eq!(node.depth(), index.depth(node.id()));
+    }
+}
+
+#[test]
+fn lowest_common_ancestor() {
+    let mut tree = tree!(0 => {
+        1 => { 2 => { 3, 4 => { 5 } }, 6 },
+        7 => { 8 => { 9 => { 10 => { 11 } } } },
+        12,
+    });
+    tree.orphan(13).append(14);
+    let index = TreeIndex::new(&tree);
+
+    for a in tree.nodes() {
+        for b in tree.nodes() {
+            assert_eq!(
+                tree.lowest_common_ancestor(a.id(), b.id()),
+                index.lowest_common_ancestor(a.id(), b.id()),
+            );
+        }
+    }
+}
@@ -234,3 +234,156 @@ fn descendants_fused() {
     assert_eq!(descendants.by_ref().count(), 5);
     assert_eq!(descendants.next(), None);
 }
+
+#[test]
+fn following() {
+    let tree = tree!('a' => { 'b' => { 'c', 'd' => { 'e' } }, 'f' => { 'g' }, 'h' });
+    let c = tree.root().first_child().unwrap().first_child().unwrap();
+    let d = c.next_sibling().unwrap();
+    assert_eq!(
+        vec![&'d', &'e', &'f', &'g', &'h'],
+        c.following().map(|n| n.value()).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![&'f', &'g', &'h'],
+        d.following().map(|n| n.value()).collect::<Vec<_>>()
+    );
+    assert_eq!(None, tree.root().following().next());
+}
+
+#[test]
+fn following_fused() {
+    let tree = tree!('a' => { 'b' => { 'c' }, 'd' });
+    let b = tree.root().first_child().unwrap();
+
+    let mut following = b.following();
+    assert_eq!(following.by_ref().count(), 1);
+    assert_eq!(following.next(), None);
+}
+
+#[test]
+fn preceding() {
+    let tree = tree!('a' => { 'b' => { 'c', 'd' }, 'e' => { 'f' => { 'g' }, 'h' } });
+    let h = tree.root().last_child().unwrap().last_child().unwrap();
+    let e = h.parent().unwrap();
+    assert_eq!(
+        vec![&'g', &'f', &'d', &'c', &'b'],
+        h.preceding().map(|n| n.value()).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![&'d', &'c', &'b'],
+        e.preceding().map(|n| n.value()).collect::<Vec<_>>()
+    );
+    assert_eq!(None, tree.root().preceding().next());
+}
+
+#[test]
+fn preceding_fused() {
+    let tree = tree!('a' => { 'b' => { 'c' }, 'd' });
+    let d = tree.root().last_child().unwrap();
+
+    let mut preceding = d.preceding();
+    assert_eq!(preceding.by_ref().count(), 2);
+    assert_eq!(preceding.next(), None);
+}
+
+#[test]
+fn document_order() {
+    let tree = tree!('a' => { 'b' => { 'c', 'd' => { 'e' } }, 'f' });
+
+    let mut forward = vec![tree.root()];
+    while let Some(next) = forward.last().unwrap().next_in_document_order() {
+        forward.push(next);
+    }
+    assert_eq!(tree.root().descendants().collect::<Vec<_>>(), forward);
+
+    let mut backward = vec![*forward.last().unwrap()];
+    while let Some(prev) = backward.last().unwrap().prev_in_document_order() {
+        backward.push(prev);
+    }
+    backward.reverse();
+    assert_eq!(forward, backward);
+}
+
+#[test]
+fn traverse_range() {
+    use ego_tree::iter::Edge;
+
+    let tree = tree!('a' => { 'b' => { 'c', 'd' }, 'e' => { 'f', 'g' } });
+    let find = |v| {
+        tree.root()
+            .descendants()
+            .find(|n| *n.value() == v)
+            .unwrap()
+            .id()
+    };
+    let range = |start, end| {
+        tree.traverse_range(find(start), find(end))
+            .map(|edge| match edge {
+                Edge::Open(node) => format!("<{}", node.value()),
+                Edge::Close(node) => format!("{}>", node.value()),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(vec!["<d", "d>", "b>", "<e", "<f", "f>"], range('d', 'f'));
+    assert_eq!(vec!["<c", "c>"], range('c', 'c'));
+    assert_eq!(
+        vec![
+            "<b", "<c", "c>", "<d", "d>", "b>", "<e", "<f", "f>", "<g", "g>", "e>"
+        ],
+        range('b', 'e')
+    );
+    assert_eq!(vec!["<b", "<c", "c>"], range('b', 'c'));
+    assert_eq!(
+        vec!["<d", "d>", "b>", "<e", "<f", "f>", "<g", "g>", "e>", "a>"],
+        range('d', 'a')
+    );
+    assert!(range('f', 'd').is_empty());
+    assert!(range('e', 'b').is_empty());
+}
+
+#[test]
+fn traverse_range_orphan() {
+    let mut tree = tree!('a' => { 'b' });
+    let orphan = tree.orphan('c').id();
+    let b = tree.root().first_child().unwrap().id();
+
+    assert_eq!(None, tree.traverse_range(b, orphan).next());
+    assert_eq!(2, tree.traverse_range(orphan, orphan).count());
+}
+
+#[test]
+fn leaves() {
+    let tree = tree!('a' => { 'b' => { 'd', 'e' => { 'f' } }, 'c' });
+    assert_eq!(
+        vec![&'d', &'f', &'c'],
+        tree.root().leaves().map(|n| n.value()).collect::<Vec<_>>()
+    );
+
+    let c = tree.root().last_child().unwrap();
+    assert_eq!(vec![c], c.leaves().collect::<Vec<_>>());
+}
+
+#[test]
+fn leaves_fused() {
+    let tree = tree!('a' => { 'b' => { 'd', 'e' }, 'c' });
+
+    let mut leaves = tree.root().leaves();
+    assert_eq!(leaves.by_ref().count(), 3);
+    assert_eq!(leaves.next(), None);
+}
+
+#[cfg(feature = "child-count")]
+#[test]
+fn children_len() {
+    let tree = tree!('a' => { 'b', 'c', 'd' });
+
+    let mut children = tree.root().children();
+    assert_eq!(3, children.len());
+    children.next();
+    children.next_back();
+    assert_eq!(1, children.len());
+    children.next();
+    assert_eq!(0, children.len());
+}
@@ -187,3 +187,157 @@ fn subtree() {
     };
     assert_eq!(tree, expected_tree);
 }
+
+#[test]
+fn to_tree_macro() {
+    let tree = tree! {
+        "root" => {
+            "a",
+            "b" => { "c", "d" => { "e" } },
+            "f",
+        }
+    };
+    let source = tree.to_tree_macro();
+    assert_eq!(
+        r#"tree! {
+    "root" => {
+        "a",
+        "b" => {
+            "c",
+            "d" => {
+                "e",
+            },
+        },
+        "f",
+    }
+}"#,
+        source,
+    );
+
+    // The output above, pasted back.
+    let pasted = tree! {
+        "root" => {
+            "a",
+            "b" => {
+                "c",
+                "d" => {
+                    "e",
+                },
+            },
+            "f",
+        }
+    };
+    assert_eq!(tree, pasted);
+}
+
+#[test]
+fn to_tree_macro_values() {
+    assert_eq!("tree! {\n    'x'\n}", tree!('x').to_tree_macro());
+
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    let tree = tree!(Some(Point { x: 0, y: -1 }) => { None });
+    assert_eq!(
+        "tree! {\n    Some(Point { x: 0, y: -1 }) => {\n        None,\n    }\n}",
+        tree.to_tree_macro(),
+    );
+    let pasted = tree! {
+        Some(Point { x: 0, y: -1 }) => {
+            None,
+        }
+    };
+    assert_eq!(tree, pasted);
+}
+
+#[test]
+fn splice_values() {
+    let values = vec!['c', 'd'];
+    let macro_tree = tree!('a' => { 'b', #..values, #..std::iter::empty(), 'e' => { #..['f'] } });
+    assert_eq!(tree!('a' => { 'b', 'c', 'd', 'e' => { 'f' } }), macro_tree);
+}
+
+#[test]
+fn splice_subtrees() {
+    let subtrees = (1..3).map(|i| tree!(i => { i * 10 }));
+    let macro_tree = tree!(0 => { @ ..subtrees, 3 });
+    assert_eq!(tree!(0 => { 1 => { 10 }, 2 => { 20 }, 3 }), macro_tree);
+}
+
+#[test]
+fn conditional() {
+    let (yes, no) = (true, false);
+    let macro_tree = tree! {
+        'a' => {
+            #if yes => 'b',
+            #if no => 'c',
+            #if yes => 'd' => { 'e', #if no => 'f' },
+            #if no => 'g' => { 'h' },
+            #if yes => @ tree!('i' => { 'j' }),
+            #if no => @ tree!('k'),
+            #if yes => 'l'
+        }
+    };
+    assert_eq!(
+        tree!('a' => { 'b', 'd' => { 'e' }, 'i' => { 'j' }, 'l' }),
+        macro_tree
+    );
+
+    let mut id = macro_tree.root().id();
+    let macro_tree = tree!('a' => { #if yes => id @ 'b' => { 'c' }, #if no => id @ 'd' });
+    assert_eq!(macro_tree.root().first_child().unwrap().id(), id);
+}
+
+#[test]
+fn bind_ids() {
+    let (a, b, c, d);
+    let macro_tree = tree! {
+        a @ 'a' => {
+            b @ 'b',
+            c @ 'c'.to_ascii_uppercase() => { d @ 'd' },
+        }
+    };
+    let root = macro_tree.root();
+    assert_eq!(root.id(), a);
+    assert_eq!(&'b', macro_tree.get(b).unwrap().value());
+    assert_eq!(&'C', macro_tree.get(c).unwrap().value());
+    assert_eq!(Some(c), macro_tree.get(d).unwrap().parent().map(|n| n.id()));
+
+    let root_id;
+    let macro_tree = tree!(root_id @ 'a');
+    assert_eq!(macro_tree.root().id(), root_id);
+}
+
+#[test]
+fn casts() {
+    let x = 1i32;
+    assert_eq!(Tree::new(1u8), tree!(x as u8));
+    assert_eq!(
+        tree!(0u8 => { 1, 2 => { 1 } }),
+        tree!(x as u8 - 1 => { x as u8, 2 => { x as u8 } })
+    );
+}
+
+#[test]
+fn if_else_values() {
+    let c = true;
+    assert_eq!(Tree::new(1), tree!(if c { 1 } else { 2 }));
+    assert_eq!(
+        tree!(1 => { 2, 3 => { 4 } }),
+        tree!(if c { 1 } else { 0 } => {
+            if c { 2 } else { 0 },
+            if !c { 0 } else { 3 } => { if c { 4 } else { 0 } },
+        })
+    );
+}
+
+#[test]
+fn range_values() {
+    assert_eq!(Tree::new(..1), tree!(..1));
+    let macro_tree = tree!(..0 => { ..1, ..2 => { ..3 } });
+    let (values, _) = macro_tree.to_parent_array();
+    assert_eq!(vec![..0, ..1, ..2, ..3], values);
+}
@@ -0,0 +1,113 @@
+use ego_tree::node_map::{Entry, NodeMap, NodeSet};
+use ego_tree::tree;
+
+#[test]
+fn insert_get_remove() {
+    let mut tree = tree!('a' => { 'b', 'c' });
+    let ids = tree.nodes().map(|n| n.id()).collect::<Vec<_>>();
+    let mut map = NodeMap::new();
+
+    assert_eq!(None, map.insert(ids[2], "c"));
+    assert_eq!(None, map.insert(ids[0], "a"));
+    assert_eq!(Some("a"), map.insert(ids[0], "A"));
+    assert_eq!(2, map.len());
+    assert_eq!(Some(&"A"), map.get(ids[0]));
+    assert_eq!(None, map.get(ids[1]));
+    assert_eq!("c", map[ids[2]]);
+
+    // Grows with the tree.
+    let d = tree.orphan('d').id();
+    map.insert(d, "d");
+    assert!(map.contains_key(d));
+
+    *map.get_mut(d).unwrap() = "D";
+    assert_eq!(Some("D"), map.remove(d));
+    assert_eq!(None, map.remove(d));
+    assert_eq!(2, map.len());
+}
+
+#[test]
+fn entry() {
+    let tree = tree!('a' => { 'b', 'c' });
+    let mut counts = NodeMap::new();
+    for node in tree.root().descendants().chain(tree.root().children()) {
+        *counts.entry(node.id()).or_insert(0) += 1;
+    }
+    assert_eq!(vec![1, 2, 2], counts.values().copied().collect::<Vec<_>>());
+
+    let root = tree.root().id();
+    match counts.entry(root) {
+        Entry::Occupied(entry) => assert_eq!(1, entry.remove()),
+        Entry::Vacant(_) => unreachable!(),
+    }
+    assert!(matches!(counts.entry(root), Entry::Vacant(_)));
+    counts.entry(root).and_modify(|c| *c += 1).or_default();
+    assert_eq!(0, counts[root]);
+    assert_eq!(3, counts.len());
+}
+
+#[test]
+fn iter() {
+    let tree = tree!('a' => { 'b', 'c', 'd' });
+    let mut map = tree
+        .nodes()
+        .filter(|n| *n.value() != 'b')
+        .map(|n| (n.id(), *n.value()))
+        .collect::<NodeMap<_>>();
+
+    let iter = map.iter();
+    assert_eq!(3, iter.len());
+    assert_eq!(
+        vec!['a', 'c', 'd'],
+        iter.map(|(id, value)| {
+            assert_eq!(tree.get(id).unwrap().value(), value);
+            *value
+        })
+        .collect::<Vec<_>>()
+    );
+
+    for (_, value) in &mut map {
+        *value = value.to_ascii_uppercase();
+    }
+    assert_eq!(
+        vec!['A', 'C', 'D'],
+        map.into_iter().map(|(_, value)| value).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn set() {
+    let tree = tree!('a' => { 'b', 'c', 'd' });
+    let mut set = tree.root().children().map(|n| n.id()).collect::<NodeSet>();
+
+    assert_eq!(3, set.len());
+    assert!(!set.contains(tree.root().id()));
+    assert!(set.insert(tree.root().id()));
+    assert!(!set.insert(tree.root().id()));
+
+    let b = tree.root().first_child().unwrap().id();
+    assert!(set.remove(b));
+    assert!(!set.remove(b));
+    assert_eq!(
+        vec![&'a', &'c', &'d'],
+        set.iter()
+            .map(|id| tree.get(id).unwrap().value())
+            .collect::<Vec<_>>()
+    );
+
+    set.clear();
+    assert!(set.is_empty());
+}
+
+#[test]
+fn eq() {
+    let tree = tree!('a' => { 'b', 'c' });
+    let c = tree.root().last_child().unwrap().id();
+    let mut one = NodeMap::new();
+    one.insert(tree.root().id(), 1);
+    let mut two = one.clone();
+    two.insert(c, 2);
+    assert_ne!(one, two);
+    two.remove(c);
+    assert_eq!(one, two);
+}
@@ -607,3 +607,101 @@ fn test_clone_subtree() {
     assert_eq!(e_children[1].parent(), Some(children[1]));
     assert_eq!(e_children[1].children().count(), 0);
 }
+
+#[test]
+fn for_each_edge() {
+    use ego_tree::iter::EdgeMut;
+
+    let mut tree = tree!('a' => { 'b' => { 'd', 'e' }, 'c' });
+    let mut edges = Vec::new();
+    tree.root_mut().for_each_edge(|edge| match edge {
+        EdgeMut::Open(mut n) => edges.push((true, *n.value())),
+        EdgeMut::Close(mut n) => edges.push((false, *n.value())),
+    });
+
+    assert_eq!(
+        vec![
+            (true, 'a'),
+            (true, 'b'),
+            (true, 'd'),
+            (false, 'd'),
+            (true, 'e'),
+            (false, 'e'),
+            (false, 'b'),
+            (true, 'c'),
+            (false, 'c'),
+            (false, 'a'),
+        ],
+        edges
+    );
+}
+
+#[test]
+fn for_each_edge_subtree_sums() {
+    use ego_tree::iter::EdgeMut;
+
+    let mut tree = tree!(1 => { 2 => { 3, 4 }, 5 => { 6 } });
+    let b = tree.root().first_child().unwrap().id();
+    tree.get_mut(b).unwrap().for_each_edge(|mut edge| {
+        if let EdgeMut::Close(node) = &mut edge {
+            let sum: i32 = node.as_ref().children().map(|c| *c.value()).sum();
+            *edge.value() += sum;
+        }
+    });
+
+    assert_eq!(tree, tree!(1 => { 9 => { 3, 4 }, 5 => { 6 } }));
+}
+
+#[test]
+fn for_each_edge_node() {
+    use ego_tree::iter::EdgeMut;
+
+    let mut tree = tree!(1 => { 2 => { 3 }, 4 });
+    let mut ids = Vec::new();
+    tree.root_mut().for_each_edge(|edge| {
+        if let EdgeMut::Open(mut node) = edge {
+            ids.push(node.id());
+            let depth = node.as_ref().ancestors().count();
+            *node.value() *= 10_i32.pow(depth as u32);
+        }
+    });
+
+    assert_eq!(tree.nodes().map(|n| n.id()).collect::<Vec<_>>(), ids);
+    assert_eq!(tree!(1 => { 20 => { 300 }, 40 }), tree);
+}
+
+#[test]
+fn child_count_consistency() {
+    let mut tree = tree!('a' => { 'b' => { 'c', 'd' }, 'e' => { 'f' }, 'g' });
+    let b = tree.root().first_child().unwrap().id();
+    let e = tree.root().nth_child(1).unwrap().id();
+    let g = tree.root().last_child().unwrap().id();
+    let f = tree.get(e).unwrap().first_child().unwrap().id();
+
+    let check = |tree: &ego_tree::Tree<char>| {
+        for node in tree.nodes() {
+            assert_eq!(node.children().count(), node.child_count());
+        }
+    };
+
+    tree.get_mut(b).unwrap().append_id(f);
+    check(&tree);
+    tree.get_mut(b).unwrap().prepend_id(g);
+    check(&tree);
+    tree.get_mut(e).unwrap().insert_id_before(g);
+    check(&tree);
+    tree.get_mut(e).unwrap().insert_id_after(f);
+    check(&tree);
+    tree.get_mut(e).unwrap().reparent_from_id_append(b);
+    check(&tree);
+    tree.get_mut(b).unwrap().reparent_from_id_prepend(e);
+    check(&tree);
+    tree.get_mut(f).unwrap().detach();
+    check(&tree);
+    tree.root_mut().append_subtree(tree!('h' => { 'i', 'j' }));
+    check(&tree);
+    tree.get_mut(b).unwrap().clone_subtree();
+    check(&tree);
+    tree.root_mut().sort();
+    check(&tree);
+}
@@ -81,3 +81,111 @@ fn neq_tree() {
     let two = one.clone();
     assert_eq!(one.root(), two.root());
 }
+
+#[test]
+fn depth() {
+    let tree = tree!('a' => { 'b' => { 'c' } });
+    let b = tree.root().first_child().unwrap();
+    assert_eq!(0, tree.root().depth());
+    assert_eq!(1, b.depth());
+    assert_eq!(2, b.first_child().unwrap().depth());
+}
+
+#[test]
+fn path_from_root() {
+    let tree = tree!('a' => { 'b', 'c' => { 'd' } });
+    let c = tree.root().last_child().unwrap();
+    let d = c.first_child().unwrap();
+    assert_eq!(vec![tree.root().id()], tree.root().path_from_root());
+    assert_eq!(vec![tree.root().id(), c.id(), d.id()], d.path_from_root());
+}
+
+#[test]
+fn index_in_parent() {
+    let mut tree = tree!('a' => { 'b', 'c', 'd' });
+    let orphan = tree.orphan('e').id();
+    let d = tree.root().last_child().unwrap();
+    assert_eq!(None, tree.root().index_in_parent());
+    assert_eq!(
+        Some(0),
+        tree.root().first_child().unwrap().index_in_parent()
+    );
+    assert_eq!(Some(2), d.index_in_parent());
+    assert_eq!(None, tree.get(orphan).unwrap().index_in_parent());
+}
+
+#[test]
+fn is_ancestor_of() {
+    let tree = tree!('a' => { 'b' => { 'c' }, 'd' });
+    let b = tree.root().first_child().unwrap();
+    let c = b.first_child().unwrap();
+    let d = tree.root().last_child().unwrap();
+    assert!(tree.root().is_ancestor_of(&c));
+    assert!(b.is_ancestor_of(&c));
+    assert!(!b.is_ancestor_of(&b));
+    assert!(!d.is_ancestor_of(&c));
+    assert!(!c.is_ancestor_of(&b));
+}
+
+#[test]
+fn is_descendant_of() {
+    let tree = tree!('a' => { 'b' => { 'c' }, 'd' });
+    let b = tree.root().first_child().unwrap();
+    let c = b.first_child().unwrap();
+    let d = tree.root().last_child().unwrap();
+    assert!(c.is_descendant_of(&tree.root()));
+    assert!(c.is_descendant_of(&b));
+    assert!(!c.is_descendant_of(&c));
+    assert!(!c.is_descendant_of(&d));
+}
+
+#[test]
+fn compare_document_position() {
+    use ego_tree::DocumentPosition;
+
+    let mut tree = tree!('a' => { 'b' => { 'c' }, 'd' });
+    let orphan = tree.orphan('e').id();
+    let b = tree.root().first_child().unwrap();
+    let c = b.first_child().unwrap();
+    let d = tree.root().last_child().unwrap();
+    let e = tree.get(orphan).unwrap();
+
+    assert_eq!(DocumentPosition::Same, b.compare_document_position(&b));
+    assert_eq!(DocumentPosition::Following, c.compare_document_position(&d));
+    assert_eq!(DocumentPosition::Preceding, d.compare_document_position(&c));
+    assert_eq!(DocumentPosition::Contains, c.compare_document_position(&b));
+    assert_eq!(
+        DocumentPosition::ContainedBy,
+        b.compare_document_position(&c)
+    );
+    assert_eq!(
+        DocumentPosition::Disconnected,
+        c.compare_document_position(&e)
+    );
+
+    let other = tree.clone();
+    assert_eq!(
+        DocumentPosition::Disconnected,
+        tree.root().compare_document_position(&other.root())
+    );
+}
+
+#[test]
+fn child_count() {
+    let tree = tree!('a' => { 'b' => { 'c' }, 'd', 'e' });
+    assert_eq!(3, tree.root().child_count());
+    assert_eq!(1, tree.root().first_child().unwrap().child_count());
+    assert_eq!(0, tree.root().last_child().unwrap().child_count());
+}
+
+#[test]
+fn nth_child() {
+    let tree = tree!('a' => { 'b', 'c', 'd', 'e' });
+    let values = (0..5)
+        .map(|n| tree.root().nth_child(n).map(|c| *c.value()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![Some('b'), Some('c'), Some('d'), Some('e'), None],
+        values
+    );
+}
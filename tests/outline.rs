@@ -0,0 +1,111 @@
+use ego_tree::outline::{OutlineError, OutlineErrorKind};
+use ego_tree::{Tree, tree};
+
+#[test]
+fn display_round_trip() {
+    let tree = tree! {
+        "root" => {
+            "a" => { "x", "y" => { "z" } },
+            "b",
+            "c" => { "w" => { "v" } },
+        }
+    };
+    let parsed = Tree::from_outline(&tree.to_string()).unwrap();
+    assert_eq!(tree.map(String::from), parsed);
+}
+
+#[test]
+fn indented() {
+    let outline = "root\n  a\n    x\n\n    y\n  b\n";
+    let tree = Tree::from_outline(outline).unwrap();
+    assert_eq!(
+        tree!("root" => { "a" => { "x", "y" }, "b" }).map(String::from),
+        tree
+    );
+
+    let tree = Tree::from_outline("root\n\ta\n\t\tx\n\tb").unwrap();
+    assert_eq!(
+        tree!("root" => { "a" => { "x" }, "b" }).map(String::from),
+        tree
+    );
+}
+
+#[test]
+fn parse_values() {
+    let tree = Tree::<u8>::parse_outline("1\n├── 2\n│   └── 3\n└── 4\n").unwrap();
+    assert_eq!(tree!(1 => { 2 => { 3 }, 4 }), tree);
+}
+
+#[test]
+fn value_error() {
+    let error = Tree::<u8>::parse_outline("1\n├── 2\n│   └── x\n└── 4\n").unwrap_err();
+    assert_eq!(3, error.line);
+    assert_eq!(9, error.column);
+    assert!(matches!(error.kind, OutlineErrorKind::Value(_)));
+    assert!(error.to_string().starts_with("3:9: invalid value"));
+}
+
+#[test]
+fn structure_errors() {
+    let kind = |s| Tree::from_outline(s).unwrap_err();
+
+    assert_eq!(
+        OutlineError {
+            line: 1,
+            column: 1,
+            kind: OutlineErrorKind::Empty
+        },
+        kind("")
+    );
+    assert_eq!(
+        OutlineError {
+            line: 2,
+            column: 1,
+            kind: OutlineErrorKind::MultipleRoots
+        },
+        kind("\nroot")
+    );
+    assert_eq!(
+        OutlineError {
+            line: 1,
+            column: 1,
+            kind: OutlineErrorKind::IndentedRoot
+        },
+        kind("  root")
+    );
+    assert_eq!(
+        OutlineError {
+            line: 3,
+            column: 1,
+            kind: OutlineErrorKind::MultipleRoots
+        },
+        kind("root\n  a\nother")
+    );
+    assert_eq!(
+        OutlineError {
+            line: 4,
+            column: 3,
+            kind: OutlineErrorKind::Indentation
+        },
+        kind("root\n a\n    b\n  c")
+    );
+}
+
+#[test]
+fn empty_root() {
+    let tree = tree!("" => { "a" => { "" }, "b" }).map(String::from);
+    assert_eq!(Ok(tree.clone()), Tree::from_outline(&tree.to_string()));
+
+    let tree = Tree::new(String::new());
+    assert_eq!(Ok(tree.clone()), Tree::from_outline(&tree.to_string()));
+    assert_eq!(Ok(tree), Tree::from_outline("\n  \n"));
+}
+
+#[test]
+fn leading_whitespace_not_kept() {
+    let tree = tree!("root" => { " b" => { "\tc" } }).map(String::from);
+    assert_eq!(
+        Ok(tree!("root" => { "b" => { "c" } }).map(String::from)),
+        Tree::from_outline(&tree.to_string())
+    );
+}
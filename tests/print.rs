@@ -0,0 +1,121 @@
+use ego_tree::print::{Glyphs, TreePrinter};
+use ego_tree::tree;
+
+#[test]
+fn matches_display() {
+    let tree = tree!("root" => { "a" => { "x", "y" => { "z" } }, "b", "c" => { "w" } });
+    assert_eq!(tree.to_string(), TreePrinter::new(tree.root()).to_string());
+}
+
+#[test]
+fn subtree() {
+    let tree = tree!("root" => { "a" => { "x", "y" }, "b" });
+    let a = tree.root().first_child().unwrap();
+    assert_eq!("a\n├── x\n└── y\n", TreePrinter::new(a).to_string());
+}
+
+#[test]
+fn ascii() {
+    let tree = tree!("root" => { "a" => { "x" }, "b" });
+    let printer = TreePrinter::new(tree.root()).glyphs(Glyphs::ASCII);
+    assert_eq!("root\n|-- a\n|   `-- x\n`-- b\n", printer.to_string());
+}
+
+#[test]
+fn custom_glyphs() {
+    let glyphs = Glyphs {
+        branch: "+ ",
+        last_branch: "\\ ",
+        vertical: "| ",
+        space: "  ",
+        ellipsis: "~",
+    };
+    let tree = tree!("root" => { "a" => { "x" }, "b", "c" });
+    let printer = TreePrinter::new(tree.root()).glyphs(glyphs).max_children(2);
+    assert_eq!("root\n+ a\n| \\ x\n+ b\n\\ ~ 1 more\n", printer.to_string());
+}
+
+#[test]
+fn render() {
+    let tree = tree!(1 => { 2, 3 });
+    let printer = TreePrinter::with_render(tree.root(), |f, node| {
+        write!(f, "{} (#{})", node.value(), node.id())
+    });
+    assert_eq!("1 (#0)\n├── 2 (#1)\n└── 3 (#2)\n", printer.to_string());
+}
+
+#[test]
+fn indent_root() {
+    let tree = tree!("root" => { "a", "b" });
+    let printer = TreePrinter::new(tree.root()).indent_root(true);
+    assert_eq!("└── root\n    ├── a\n    └── b\n", printer.to_string());
+}
+
+#[test]
+fn max_depth() {
+    let tree = tree!("root" => { "a" => { "x" => { "deep" }, "y" }, "b" });
+
+    let printer = TreePrinter::new(tree.root()).max_depth(1);
+    assert_eq!(
+        "root\n├── a\n│   └── … 2 more\n└── b\n",
+        printer.to_string()
+    );
+
+    let printer = TreePrinter::new(tree.root()).max_depth(0);
+    assert_eq!("root\n└── … 2 more\n", printer.to_string());
+}
+
+#[test]
+fn max_children() {
+    let tree = tree!(0 => { 1, 2, 3, 4 => { 5, 6 } });
+    let printer = TreePrinter::new(tree.root()).max_children(1);
+    assert_eq!("0\n├── 1\n└── … 3 more\n", printer.to_string());
+
+    let printer = TreePrinter::new(tree.root()).max_children(4);
+    assert_eq!(
+        "0\n├── 1\n├── 2\n├── 3\n└── 4\n    ├── 5\n    └── 6\n",
+        printer.to_string()
+    );
+}
+
+#[test]
+fn debug_structure() {
+    let tree = tree!(1 => { 2 => { 3 }, 4 });
+    assert_eq!(
+        "0: 1\n├── 1: 2\n│   └── 2: 3\n└── 3: 4\n",
+        format!("{:?}", tree.debug_structure()),
+    );
+}
+
+#[test]
+fn debug_structure_orphans() {
+    let mut tree = tree!('a' => { 'b' => { 'c' }, 'd' });
+    let b = tree.root().first_child().unwrap().id();
+    tree.get_mut(b).unwrap().detach();
+    let e = tree.orphan('e').append('f').id();
+    tree.get_mut(e).unwrap().append('g');
+
+    assert_eq!(
+        concat!(
+            "0: 'a'\n",
+            "└── 3: 'd'\n",
+            "orphans:\n",
+            "1: 'b'\n",
+            "└── 2: 'c'\n",
+            "4: 'e'\n",
+            "└── 5: 'f'\n",
+            "    └── 6: 'g'\n",
+        ),
+        format!("{:?}", tree.debug_structure()),
+    );
+}
+
+#[test]
+fn debug_structure_copy() {
+    fn assert_copy<T: Copy>(_: T) {}
+
+    let tree = tree!("a".to_string() => { "b".to_string() });
+    let view = tree.debug_structure();
+    assert_copy(view);
+    assert_eq!(format!("{view:?}"), format!("{:?}", view.clone()));
+}
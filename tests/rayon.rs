@@ -0,0 +1,70 @@
+#![cfg(feature = "rayon")]
+
+use ego_tree::{Tree, tree};
+use rayon::prelude::*;
+
+#[test]
+fn par_values() {
+    let tree = tree!(1 => { 2, 3 => { 4 } });
+    assert_eq!(10, tree.par_values().sum::<i32>());
+    assert_eq!(vec![&1, &2, &3, &4], tree.par_values().collect::<Vec<_>>());
+}
+
+#[test]
+fn par_values_mut() {
+    let mut tree = tree!(1 => { 2, 3 => { 4 } });
+    tree.par_values_mut().for_each(|value| *value *= 10);
+    assert_eq!(tree!(10 => { 20, 30 => { 40 } }), tree);
+}
+
+#[test]
+fn par_map() {
+    let tree = tree!(1 => { 2, 3 => { 4 } });
+    let mapped = tree.clone().par_map(|value| value.to_string());
+    assert_eq!(tree.map(|value| value.to_string()), mapped);
+}
+
+#[test]
+fn par_for_each_descendant() {
+    let mut tree = tree!(1 => { 2 => { 3, 4 }, 5 => { 6 } });
+    tree.orphan(7);
+    let b = tree.root().first_child().unwrap().id();
+
+    tree.get_mut(b)
+        .unwrap()
+        .par_for_each_descendant(|value| *value += 10);
+    assert_eq!(
+        vec![&1, &12, &13, &14, &5, &6, &7],
+        tree.values().collect::<Vec<_>>()
+    );
+
+    tree.root_mut()
+        .par_for_each_descendant(|value| *value += 100);
+    assert_eq!(
+        vec![&101, &112, &113, &114, &105, &106, &7],
+        tree.values().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn par_for_each_descendant_shapes() {
+    // Deep chain.
+    let mut tree = Tree::new(0);
+    let mut id = tree.root().id();
+    for i in 1..100_000 {
+        id = tree.get_mut(id).unwrap().append(i).id();
+    }
+    tree.root_mut().par_for_each_descendant(|value| *value += 1);
+    assert!(tree.values().enumerate().all(|(i, v)| *v == i + 1));
+
+    // Wide and bushy.
+    let mut tree = Tree::new(0);
+    for _ in 0..100 {
+        let child = tree.root_mut().append(0).id();
+        for _ in 0..100 {
+            tree.get_mut(child).unwrap().append(0).append(0);
+        }
+    }
+    tree.root_mut().par_for_each_descendant(|value| *value += 1);
+    assert!(tree.values().all(|v| *v == 1));
+}
@@ -0,0 +1,174 @@
+use ego_tree::summary::{Summary, SummaryTree};
+use ego_tree::{Tree, tree};
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Len(usize);
+
+impl Summary<String> for Len {
+    fn from_value(value: &String) -> Self {
+        Len(value.len())
+    }
+
+    fn combine(&mut self, other: &Self) {
+        self.0 += other.0;
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Max(u32);
+
+impl Summary<u32> for Max {
+    fn from_value(value: &u32) -> Self {
+        Max(*value)
+    }
+
+    fn combine(&mut self, other: &Self) {
+        self.0 = self.0.max(other.0);
+    }
+}
+
+fn strings(tree: Tree<&str>) -> SummaryTree<String, Len> {
+    tree.map(String::from).into()
+}
+
+fn assert_consistent<T: Clone, S: Summary<T> + std::fmt::Debug + PartialEq>(
+    tree: &SummaryTree<T, S>,
+) {
+    let fresh = SummaryTree::<T, S>::from(tree.tree().clone());
+    for node in tree.tree().nodes() {
+        assert_eq!(fresh.summary(node.id()), tree.summary(node.id()));
+    }
+}
+
+#[test]
+fn from_tree() {
+    let tree = strings(tree!("a" => { "bc" => { "def" }, "g" }));
+    let root = tree.tree().root();
+    assert_eq!(Some(&Len(7)), tree.summary(root.id()));
+    assert_eq!(
+        Some(&Len(5)),
+        tree.summary(root.first_child().unwrap().id())
+    );
+}
+
+#[test]
+fn update() {
+    let mut tree = SummaryTree::<_, Max>::from(tree!(1 => { 2 => { 3 }, 4 }));
+    let root = tree.tree().root().id();
+    let leaf = tree
+        .tree()
+        .root()
+        .first_child()
+        .unwrap()
+        .first_child()
+        .unwrap()
+        .id();
+    assert_eq!(Some(&Max(4)), tree.summary(root));
+
+    tree.update(leaf, |value| *value = 10);
+    assert_eq!(Some(&Max(10)), tree.summary(root));
+    assert_consistent(&tree);
+}
+
+#[test]
+fn edits() {
+    let mut tree = strings(tree!("a" => { "bc" => { "def" }, "g" }));
+    let root = tree.tree().root().id();
+    let bc = tree.tree().root().first_child().unwrap().id();
+    let g = tree.tree().root().last_child().unwrap().id();
+
+    let h = tree.append(bc, "hhhh".into());
+    assert_eq!(Some(&Len(11)), tree.summary(root));
+    tree.prepend(g, "ii".into());
+    tree.insert_before(bc, "j".into());
+    tree.insert_after(h, "kk".into());
+    assert_consistent(&tree);
+
+    tree.append_id(g, h);
+    tree.prepend_id(bc, g);
+    tree.insert_id_before(bc, h);
+    tree.insert_id_after(h, g);
+    assert_consistent(&tree);
+
+    tree.reparent_from_id_append(g, bc);
+    assert_consistent(&tree);
+    tree.reparent_from_id_prepend(bc, g);
+    assert_consistent(&tree);
+    tree.sort(bc);
+    tree.sort_by(root, |a, b| b.value().cmp(a.value()));
+    tree.sort_by_key(h, |node| node.value().len());
+    assert_consistent(&tree);
+
+    tree.detach(bc);
+    assert_consistent(&tree);
+    assert_eq!(Some(&Len(7)), tree.summary(root));
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Concat(String);
+
+impl Summary<String> for Concat {
+    fn from_value(value: &String) -> Self {
+        Concat(value.clone())
+    }
+
+    fn combine(&mut self, other: &Self) {
+        self.0.push_str(&other.0);
+    }
+}
+
+#[test]
+fn reparent_and_sort() {
+    let tree = tree!("a" => { "b" => { "e", "c" }, "d" => { "g", "f" } });
+    let mut tree = SummaryTree::<_, Concat>::from(tree.map(String::from));
+    let root = tree.tree().root().id();
+    let b = tree.tree().root().first_child().unwrap().id();
+    let d = tree.tree().root().last_child().unwrap().id();
+    let summary = |tree: &SummaryTree<String, Concat>, id| tree.summary(id).unwrap().0.clone();
+    assert_eq!("abecdgf", summary(&tree, root));
+
+    tree.reparent_from_id_append(b, d);
+    assert_consistent(&tree);
+    assert_eq!("abecgfd", summary(&tree, root));
+
+    tree.sort(b);
+    assert_consistent(&tree);
+    assert_eq!("bcefg", summary(&tree, b));
+
+    tree.reparent_from_id_prepend(d, b);
+    assert_consistent(&tree);
+    assert_eq!("abdcefg", summary(&tree, root));
+
+    tree.sort_by(d, |x, y| y.value().cmp(x.value()));
+    assert_consistent(&tree);
+    assert_eq!("dgfec", summary(&tree, d));
+
+    tree.sort_by_key(root, |node| std::cmp::Reverse(node.value().clone()));
+    assert_consistent(&tree);
+    assert_eq!("adgfecb", summary(&tree, root));
+}
+
+#[test]
+fn seek() {
+    let tree = strings(tree!("ab" => { "cde" => { "f" }, "", "gh" }));
+    let offsets = (0..8)
+        .map(|k| {
+            tree.seek(|len| len.0 > k)
+                .map(|(node, prefix)| (node.value().as_str(), prefix.0))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            Some(("ab", 0)),
+            Some(("ab", 0)),
+            Some(("cde", 2)),
+            Some(("cde", 2)),
+            Some(("cde", 2)),
+            Some(("f", 5)),
+            Some(("gh", 6)),
+            Some(("gh", 6)),
+        ],
+        offsets
+    );
+    assert_eq!(None, tree.seek(|len| len.0 > 8));
+}
@@ -238,3 +238,121 @@ fn test_display() {
 
     assert_eq!(repr, expected);
 }
+
+#[test]
+fn test_display_ignores_format_flags() {
+    let tree = tree!(1 => { 2, 3 });
+    assert_eq!(format!("{tree}"), format!("{tree:>4}"));
+    assert_eq!(format!("{tree}"), format!("{tree:.1}"));
+
+    let tree = tree!(1.25 => { 2.5 });
+    assert_eq!("1.25\n└── 2.5\n", format!("{tree:.1}"));
+}
+
+#[test]
+fn lowest_common_ancestor() {
+    let mut tree = tree!('a' => { 'b' => { 'c', 'd' }, 'e' });
+    let orphan = tree.orphan('f').id();
+    let b = tree.root().first_child().unwrap();
+    let c = b.first_child().unwrap().id();
+    let d = b.last_child().unwrap().id();
+    let e = tree.root().last_child().unwrap().id();
+
+    assert_eq!(Some(b), tree.lowest_common_ancestor(c, d));
+    assert_eq!(Some(tree.root()), tree.lowest_common_ancestor(c, e));
+    assert_eq!(Some(b), tree.lowest_common_ancestor(b.id(), d));
+    assert_eq!(tree.get(c), tree.lowest_common_ancestor(c, c));
+    assert_eq!(None, tree.lowest_common_ancestor(c, orphan));
+}
+
+#[test]
+fn test_try_map() {
+    let str_tree = tree!("1" => { "2" => { "3" }, "4" });
+
+    let int_tree = str_tree.clone().try_map(|value| value.parse::<i32>());
+    assert_eq!(Ok(tree!(1 => { 2 => { 3 }, 4 })), int_tree);
+
+    let mut calls = 0;
+    let bad_tree = tree!("1" => { "x", "3" }).try_map(|value| {
+        calls += 1;
+        value.parse::<i32>()
+    });
+    assert!(bad_tree.is_err());
+    assert_eq!(2, calls);
+}
+
+#[test]
+fn test_map_with_node() {
+    let mut tree = tree!('a' => { 'b' => { 'c' }, 'd' });
+    tree.orphan('e');
+
+    let mapped = tree.map_with_node(|node| {
+        (
+            *node.value(),
+            node.parent().map(|parent| *parent.value()),
+            node.has_siblings(),
+        )
+    });
+
+    assert_eq!(
+        vec![
+            ('a', None, false),
+            ('b', Some('a'), true),
+            ('c', Some('b'), false),
+            ('d', Some('a'), true),
+            ('e', None, false),
+        ],
+        mapped.values().copied().collect::<Vec<_>>()
+    );
+    for node in tree.nodes() {
+        let mapped_node = mapped.get(node.id()).unwrap();
+        assert_eq!(
+            node.parent().map(|n| n.id()),
+            mapped_node.parent().map(|n| n.id())
+        );
+    }
+}
+
+#[test]
+fn node_id_from_index() {
+    let mut tree = tree!('a' => { 'b' });
+    let c = tree.orphan('c').id();
+
+    assert_eq!(Some(tree.root().id()), tree.node_id_from_index(0));
+    assert_eq!(Some(c), tree.node_id_from_index(c.index()));
+    assert_eq!(None, tree.node_id_from_index(3));
+    assert_eq!(None, tree.node_id_from_index(usize::MAX));
+
+    for node in tree.nodes() {
+        assert_eq!(Some(node.id()), tree.node_id_from_index(node.id().index()));
+    }
+}
+
+#[test]
+fn node_id_display_from_str() {
+    use ego_tree::NodeId;
+
+    let tree = tree!('a' => { 'b', 'c' });
+    let c = tree.root().last_child().unwrap().id();
+
+    assert_eq!("0", tree.root().id().to_string());
+    assert_eq!("2", c.to_string());
+    assert_eq!(Ok(c), c.to_string().parse::<NodeId>());
+    assert!("x".parse::<NodeId>().is_err());
+    assert!("-1".parse::<NodeId>().is_err());
+    assert!(usize::MAX.to_string().parse::<NodeId>().is_err());
+}
+
+#[test]
+fn node_id_debug_matches_display() {
+    let tree = tree!('a' => { 'b', 'c' });
+    for node in tree.nodes() {
+        let id = node.id();
+        assert_eq!(format!("NodeId({id})"), format!("{id:?}"));
+    }
+    assert_eq!("NodeId(0)", format!("{:?}", tree.root().id()));
+
+    let debug = format!("{tree:?}");
+    assert!(debug.contains("parent: Some(NodeId(0))"));
+    assert!(!debug.contains("NodeId(3)"));
+}